@@ -0,0 +1,277 @@
+use crate::new_hash_set;
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+use fnv::FnvHashSet;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ring/donut shape, a circle with a circular hole in the middle
+///
+/// The inner edge is part of the shape, so a point exactly `inner_radius` from the center is
+/// contained
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Annulus {
+    center: Coord,
+    inner_radius: usize,
+    outer_radius: usize,
+}
+
+impl IntersectsContains for Annulus {}
+
+impl Annulus {
+    #[must_use]
+    pub fn new<P: Into<Coord>>(center: P, inner_radius: usize, outer_radius: usize) -> Self {
+        debug_assert!(inner_radius <= outer_radius);
+        Self {
+            center: center.into(),
+            inner_radius,
+            outer_radius,
+        }
+    }
+}
+
+impl Annulus {
+    /// Radius of the hole
+    #[inline]
+    #[must_use]
+    pub fn inner_radius(&self) -> usize {
+        self.inner_radius
+    }
+
+    /// Radius of the whole shape
+    #[inline]
+    #[must_use]
+    pub fn outer_radius(&self) -> usize {
+        self.outer_radius
+    }
+
+    /// Width of the ring (distance from inner edge to outer edge)
+    #[inline]
+    #[must_use]
+    pub fn thickness(&self) -> usize {
+        self.outer_radius - self.inner_radius
+    }
+}
+
+impl Shape for Annulus {
+    /// must be [center, inner_edge, outer_edge]
+    fn from_points(points: &[Coord]) -> Self
+    where
+        Self: Sized,
+    {
+        debug_assert!(points.len() >= 3);
+        let inner_radius = points[0].distance(points[1]);
+        let outer_radius = points[0].distance(points[2]);
+        Annulus::new(points[0], inner_radius, outer_radius)
+    }
+
+    /// must be [center, inner_edge, outer_edge]
+    fn rebuild(&self, points: &[Coord]) -> Self
+    where
+        Self: Sized,
+    {
+        Annulus::from_points(points)
+    }
+
+    fn translate_by(&self, delta: Coord) -> Self {
+        Annulus::new(self.center + delta, self.inner_radius, self.outer_radius)
+    }
+
+    fn move_to(&self, point: Coord) -> Self {
+        Annulus::new(point, self.inner_radius, self.outer_radius)
+    }
+
+    fn move_center_to(&self, point: Coord) -> Self
+    where
+        Self: Sized,
+    {
+        Annulus::new(point, self.inner_radius, self.outer_radius)
+    }
+
+    fn contains(&self, point: Coord) -> bool {
+        let dist = self.center.distance(point);
+        self.inner_radius <= dist && dist <= self.outer_radius
+    }
+
+    /// Returns [center, inner_edge_at_0_degrees, outer_edge_at_0_degrees]
+    fn points(&self) -> Vec<Coord> {
+        vec![
+            self.center,
+            Coord::from_angle(self.center, self.inner_radius, 0),
+            Coord::from_angle(self.center, self.outer_radius, 0),
+        ]
+    }
+
+    #[inline]
+    fn center(&self) -> Coord {
+        self.center
+    }
+
+    #[inline]
+    fn left(&self) -> isize {
+        self.center.x - (self.outer_radius as isize)
+    }
+
+    #[inline]
+    fn right(&self) -> isize {
+        self.center.x + (self.outer_radius as isize)
+    }
+
+    #[inline]
+    fn top(&self) -> isize {
+        self.center.y - (self.outer_radius as isize)
+    }
+
+    #[inline]
+    fn bottom(&self) -> isize {
+        self.center.y + (self.outer_radius as isize)
+    }
+
+    fn outline_pixels(&self) -> Vec<Coord> {
+        let mut output = new_hash_set();
+        output.extend(self.as_outer_circle().outline_pixels());
+        output.extend(self.as_inner_circle().outline_pixels());
+        output.into_iter().collect()
+    }
+
+    fn filled_pixels(&self) -> Vec<Coord> {
+        let inner = self.as_inner_circle();
+        let outer = self.as_outer_circle();
+        let inner_outline = inner.outline_pixels();
+        let mut hole: FnvHashSet<Coord> = inner.filled_pixels().into_iter().collect();
+        for point in &inner_outline {
+            hole.remove(point);
+        }
+        let mut output = new_hash_set();
+        output.extend(outer.filled_pixels());
+        output.extend(outer.outline_pixels());
+        output.extend(inner_outline);
+        output.retain(|point| !hole.contains(point));
+        output.into_iter().collect()
+    }
+
+    fn convex_hull(&self) -> Polygon {
+        Polygon::convex_hull(&self.as_outer_circle().as_polygon().points())
+    }
+
+    fn buffer(&self, distance: usize) -> Polygon {
+        self.as_outer_circle().buffer(distance)
+    }
+
+    fn signed_distance(&self, point: Coord) -> f32 {
+        let dx = (point.x - self.center.x) as f32;
+        let dy = (point.y - self.center.y) as f32;
+        let middle = (self.inner_radius + self.outer_radius) as f32 / 2.0;
+        ((dx * dx + dy * dy).sqrt() - middle).abs() - self.thickness() as f32 / 2.0
+    }
+
+    fn to_shape_box(&self) -> ShapeBox {
+        ShapeBox::Annulus(self.clone())
+    }
+}
+
+impl Annulus {
+    /// Circle matching the hole
+    #[must_use]
+    pub fn as_inner_circle(&self) -> Circle {
+        Circle::new(self.center, self.inner_radius)
+    }
+
+    /// Circle matching the outer edge
+    #[must_use]
+    pub fn as_outer_circle(&self) -> Circle {
+        Circle::new(self.center, self.outer_radius)
+    }
+
+    /// Rectangle that surrounds the annulus
+    #[must_use]
+    pub fn as_outer_rect(&self) -> Rect {
+        Rect::new((self.left(), self.top()), (self.right(), self.bottom()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn contains_excludes_hole() {
+        let annulus = Annulus::new((50, 50), 10, 20);
+        assert!(!annulus.contains(coord!(50, 50)));
+        assert!(!annulus.contains(coord!(55, 50)));
+        assert!(annulus.contains(coord!(60, 50)));
+        assert!(annulus.contains(coord!(65, 50)));
+        assert!(annulus.contains(coord!(70, 50)));
+        assert!(!annulus.contains(coord!(71, 50)));
+    }
+
+    #[test]
+    fn filled_only_band() {
+        let annulus = Annulus::new((0, 0), 3, 6);
+        let points = annulus.filled_pixels();
+        assert!(!points.contains(&coord!(0, 0)));
+        assert!(!points.contains(&coord!(1, 1)));
+        assert!(points.contains(&coord!(3, 0)));
+        assert!(points.contains(&coord!(5, 0)));
+        assert!(points.contains(&coord!(0, -6)));
+        assert!(!points.contains(&coord!(7, 0)));
+        for point in points {
+            assert!(annulus.contains(point), "{point:?}");
+        }
+    }
+
+    #[test]
+    fn outline_has_both_edges() {
+        let annulus = Annulus::new((0, 0), 3, 6);
+        let points = annulus.outline_pixels();
+        assert!(points.contains(&coord!(3, 0)));
+        assert!(points.contains(&coord!(6, 0)));
+        assert!(!points.contains(&coord!(4, 0)));
+        assert!(!points.contains(&coord!(0, 0)));
+    }
+
+    #[test]
+    fn transform() {
+        let annulus = Annulus::new((100, 100), 10, 20);
+        let moved = annulus.move_center_to(coord!(50, 50));
+        assert_eq!(moved, Annulus::new((50, 50), 10, 20));
+
+        let scaled = annulus.scale(2.0);
+        assert_eq!(scaled, Annulus::new((100, 100), 20, 40));
+    }
+
+    #[test]
+    fn shapes_in_band() {
+        let annulus = Annulus::new((50, 50), 10, 30);
+        assert!(annulus.contains_rect(&Rect::new((65, 45), (70, 50))));
+        assert!(annulus.contains_circle(&Circle::new((70, 50), 5)));
+        assert!(annulus.contains_line(&Line::new((65, 45), (70, 55))));
+    }
+
+    #[test]
+    fn shapes_over_hole() {
+        let annulus = Annulus::new((50, 50), 10, 30);
+        assert!(!annulus.contains_rect(&Rect::new((45, 45), (55, 55))));
+        assert!(!annulus.contains_rect(&Rect::new((30, 30), (70, 70))));
+        assert!(!annulus.contains_circle(&Circle::new((50, 50), 3)));
+        assert!(!annulus.contains_line(&Line::new((25, 50), (75, 50))));
+    }
+
+    #[test]
+    fn contains_annulus_in_band() {
+        let annulus = Annulus::new((50, 50), 10, 20);
+        assert!(annulus.contains_annulus(&Annulus::new((50, 50), 12, 18)));
+        assert!(!annulus.contains_annulus(&Annulus::new((50, 50), 5, 18)));
+        assert!(!annulus.contains_annulus(&Annulus::new((50, 50), 2, 8)));
+        assert!(annulus.contains_circle(&Circle::new((65, 50), 3)));
+        assert!(!annulus.contains_circle(&Circle::new((50, 50), 5)));
+    }
+
+    #[test]
+    fn contained_by_other_shapes() {
+        let annulus = Annulus::new((50, 50), 10, 20);
+        assert!(Rect::new((0, 0), (100, 100)).contains_annulus(&annulus));
+        assert!(!Rect::new((40, 40), (100, 100)).contains_annulus(&annulus));
+    }
+}
@@ -0,0 +1,262 @@
+use crate::general_math::distance_to_segment;
+use crate::new_hash_set;
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+use fnv::FnvHashSet;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stadium shape, every point within `radius` of the line from `start` to `end`
+///
+/// Commonly used for character collision volumes
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Capsule {
+    start: Coord,
+    end: Coord,
+    radius: usize,
+}
+
+impl IntersectsContains for Capsule {}
+
+impl Capsule {
+    #[must_use]
+    pub fn new<P1: Into<Coord>, P2: Into<Coord>>(start: P1, end: P2, radius: usize) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+            radius,
+        }
+    }
+}
+
+impl Capsule {
+    /// Center of the first cap
+    #[inline]
+    #[must_use]
+    pub fn start(&self) -> Coord {
+        self.start
+    }
+
+    /// Center of the second cap
+    #[inline]
+    #[must_use]
+    pub fn end(&self) -> Coord {
+        self.end
+    }
+
+    /// Distance from the center line to the edge
+    #[inline]
+    #[must_use]
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Distance from `point` to the closest point on the center line
+    #[must_use]
+    pub fn distance_from_spine<P: Into<Coord>>(&self, point: P) -> usize {
+        let point = point.into();
+        self.as_line().nearest_point(point).distance(point)
+    }
+}
+
+impl Shape for Capsule {
+    /// must be [start, end, edge]
+    fn from_points(points: &[Coord]) -> Self
+    where
+        Self: Sized,
+    {
+        debug_assert!(points.len() >= 3);
+        let radius = points[0].distance(points[2]);
+        Capsule::new(points[0], points[1], radius)
+    }
+
+    /// must be [start, end, edge]
+    fn rebuild(&self, points: &[Coord]) -> Self
+    where
+        Self: Sized,
+    {
+        Capsule::from_points(points)
+    }
+
+    fn translate_by(&self, delta: Coord) -> Self {
+        Capsule::new(self.start + delta, self.end + delta, self.radius)
+    }
+
+    fn contains(&self, point: Coord) -> bool {
+        self.distance_from_spine(point) <= self.radius
+    }
+
+    /// Returns [start, end, edge_of_start_at_0_degrees]
+    fn points(&self) -> Vec<Coord> {
+        vec![
+            self.start,
+            self.end,
+            Coord::from_angle(self.start, self.radius, 0),
+        ]
+    }
+
+    fn center(&self) -> Coord {
+        self.start.mid_point(self.end)
+    }
+
+    #[inline]
+    fn left(&self) -> isize {
+        self.start.x.min(self.end.x) - self.radius as isize
+    }
+
+    #[inline]
+    fn right(&self) -> isize {
+        self.start.x.max(self.end.x) + self.radius as isize
+    }
+
+    #[inline]
+    fn top(&self) -> isize {
+        self.start.y.min(self.end.y) - self.radius as isize
+    }
+
+    #[inline]
+    fn bottom(&self) -> isize {
+        self.start.y.max(self.end.y) + self.radius as isize
+    }
+
+    fn outline_pixels(&self) -> Vec<Coord> {
+        let filled: FnvHashSet<Coord> = self.filled_pixels().into_iter().collect();
+        filled
+            .iter()
+            .filter(|point| {
+                [(0, -1), (1, 0), (0, 1), (-1, 0)]
+                    .iter()
+                    .any(|offset| !filled.contains(&(**point + *offset)))
+            })
+            .copied()
+            .collect()
+    }
+
+    fn filled_pixels(&self) -> Vec<Coord> {
+        let mut output = new_hash_set();
+        for y in self.top()..=self.bottom() {
+            for x in self.left()..=self.right() {
+                let point = coord!(x, y);
+                if self.contains(point) {
+                    output.insert(point);
+                }
+            }
+        }
+        output.into_iter().collect()
+    }
+
+    fn convex_hull(&self) -> Polygon {
+        Polygon::convex_hull(&self.as_polygon().points())
+    }
+
+    fn buffer(&self, distance: usize) -> Polygon {
+        Capsule::new(self.start, self.end, self.radius + distance).as_polygon()
+    }
+
+    fn signed_distance(&self, point: Coord) -> f32 {
+        distance_to_segment(point, self.start, self.end) - self.radius as f32
+    }
+
+    fn to_shape_box(&self) -> ShapeBox {
+        ShapeBox::Capsule(self.clone())
+    }
+}
+
+impl Capsule {
+    /// Line from `start` to `end`
+    #[must_use]
+    pub fn as_line(&self) -> Line {
+        Line::new(self.start, self.end)
+    }
+
+    /// Rectangle that surrounds the capsule
+    #[must_use]
+    pub fn as_outer_rect(&self) -> Rect {
+        Rect::new((self.left(), self.top()), (self.right(), self.bottom()))
+    }
+
+    /// Polygon approximating the capsule, the caps are made of several short lines
+    #[must_use]
+    pub fn as_polygon(&self) -> Polygon {
+        let angle = self.start.angle_to(self.end);
+        let segments = ((self.radius as f64) * 10.0).sqrt().floor().max(4.0) as usize;
+        let step = 180.0 / segments as f64;
+        let mut points = vec![];
+        for (center, start_angle) in [(self.end, angle - 90), (self.start, angle + 90)] {
+            for i in 0..=segments {
+                let degrees = start_angle + (step * i as f64).round() as isize;
+                points.push(Coord::from_angle(center, self.radius, degrees));
+            }
+        }
+        Polygon::new(&points)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn contains() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        assert!(capsule.contains(coord!(40, 20)));
+        assert!(capsule.contains(coord!(40, 30)));
+        assert!(!capsule.contains(coord!(40, 31)));
+        assert!(capsule.contains(coord!(10, 20)));
+        assert!(capsule.contains(coord!(70, 20)));
+        assert!(!capsule.contains(coord!(71, 20)));
+        assert!(capsule.contains(coord!(67, 27)));
+        assert!(!capsule.contains(coord!(69, 28)));
+    }
+
+    #[test]
+    fn pixels() {
+        let capsule = Capsule::new((0, 0), (10, 0), 3);
+        let filled = capsule.filled_pixels();
+        let outline = capsule.outline_pixels();
+        assert!(filled.contains(&coord!(5, 0)));
+        assert!(filled.contains(&coord!(5, 3)));
+        assert!(filled.contains(&coord!(-3, 0)));
+        assert!(!filled.contains(&coord!(5, 4)));
+        assert!(outline.contains(&coord!(5, 3)));
+        assert!(outline.contains(&coord!(13, 0)));
+        assert!(!outline.contains(&coord!(5, 0)));
+        for point in &outline {
+            assert!(filled.contains(point));
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        assert_eq!(capsule.center(), coord!(40, 20));
+        let moved = capsule.move_center_to(coord!(100, 100));
+        assert_eq!(moved, Capsule::new((80, 100), (120, 100), 10));
+        let rotated = capsule.rotate(90);
+        assert_eq!(rotated, Capsule::new((40, 0), (40, 40), 10));
+    }
+
+    #[test]
+    fn polygon() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let polygon = capsule.as_polygon();
+        assert!(polygon.is_convex());
+        assert_eq!(polygon.left(), 10);
+        assert_eq!(polygon.right(), 70);
+        assert_eq!(polygon.top(), 10);
+        assert_eq!(polygon.bottom(), 30);
+    }
+
+    #[test]
+    fn contains_shapes() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        assert!(capsule.contains_rect(&Rect::new((20, 15), (60, 25))));
+        assert!(!capsule.contains_rect(&Rect::new((10, 10), (70, 30))));
+        assert!(capsule.contains_circle(&Circle::new((30, 20), 10)));
+        assert!(!capsule.contains_circle(&Circle::new((30, 20), 11)));
+        assert!(capsule.contains_capsule(&Capsule::new((25, 20), (55, 20), 5)));
+        assert!(Rect::new((0, 0), (80, 40)).contains_capsule(&capsule));
+        assert!(!Rect::new((15, 0), (80, 40)).contains_capsule(&capsule));
+    }
+}
@@ -153,6 +153,20 @@ impl Shape for Circle {
         output.into_iter().collect()
     }
 
+    fn convex_hull(&self) -> Polygon {
+        Polygon::convex_hull(&self.as_polygon().points())
+    }
+
+    fn buffer(&self, distance: usize) -> Polygon {
+        Circle::new(self.center, self.radius + distance).as_polygon()
+    }
+
+    fn signed_distance(&self, point: Coord) -> f32 {
+        let dx = (point.x - self.center.x) as f32;
+        let dy = (point.y - self.center.y) as f32;
+        (dx * dx + dy * dy).sqrt() - self.radius as f32
+    }
+
     fn to_shape_box(&self) -> ShapeBox {
         ShapeBox::Circle(self.clone())
     }
@@ -201,6 +215,12 @@ impl Circle {
     pub fn as_ellipse(&self) -> Ellipse {
         Ellipse::new(self.center, self.radius * 2, self.radius * 2)
     }
+
+    /// Polygon approximating the circle, see [Ellipse::as_polygon]
+    #[must_use]
+    pub fn as_polygon(&self) -> Polygon {
+        self.as_ellipse().as_polygon()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,454 @@
+use crate::distance::{closest_points_moved, fcoord, length, scale, sub};
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Details of a collision between two shapes, see [Collide::collide]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Manifold {
+    depth: f32,
+    normal: (f32, f32),
+}
+
+impl Manifold {
+    /// How far the shapes overlap
+    #[inline]
+    #[must_use]
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    /// Direction (length of 1) from the first shape towards the second shape
+    #[inline]
+    #[must_use]
+    pub fn normal(&self) -> (f32, f32) {
+        self.normal
+    }
+
+    /// Minimum translation vector, the shortest move for the first shape that separates the shapes
+    /// (they may still be touching)
+    ///
+    /// Move the second shape by the negative of this instead to separate them that way
+    #[must_use]
+    pub fn mtv(&self) -> (f32, f32) {
+        (-self.normal.0 * self.depth, -self.normal.1 * self.depth)
+    }
+
+    /// [mtv](Manifold::mtv) rounded away from zero so it can be used with [Shape::translate_by]
+    #[must_use]
+    pub fn mtv_coord(&self) -> Coord {
+        let (x, y) = self.mtv();
+        let away = |value: f32| {
+            if value.abs() < 1e-4 {
+                0.0
+            } else {
+                value.abs().ceil().copysign(value)
+            }
+        };
+        coord!(away(x), away(y))
+    }
+}
+
+/// Convex outline used for collision checks
+#[derive(Debug, Clone, PartialEq)]
+pub enum Convex {
+    /// Corners in order (either direction)
+    Polygon(Vec<(f32, f32)>),
+    Circle {
+        center: (f32, f32),
+        radius: f32,
+    },
+}
+
+/// Collision detection using the Separating Axis Theorem
+///
+/// Unlike [IntersectsShape] this also detects shapes that are fully inside each other
+pub trait Collide {
+    /// Convex outline of the shape, non convex shapes use their [convex hull](Shape::convex_hull)
+    #[must_use]
+    fn as_convex(&self) -> Convex;
+
+    /// Returns how `self` and `other` overlap or None if they don't overlap
+    ///
+    /// Shapes that are only touching return a [Manifold] with a depth of 0
+    #[must_use]
+    fn collide<C: Collide>(&self, other: &C) -> Option<Manifold>
+    where
+        Self: Sized,
+    {
+        sat(&self.as_convex(), &other.as_convex())
+    }
+}
+
+impl Collide for Rect {
+    fn as_convex(&self) -> Convex {
+        polygon_convex(&self.as_polygon().points())
+    }
+}
+
+impl Collide for Triangle {
+    fn as_convex(&self) -> Convex {
+        polygon_convex(&self.points())
+    }
+}
+
+impl Collide for Polygon {
+    fn as_convex(&self) -> Convex {
+        if self.is_convex() {
+            polygon_convex(&self.points())
+        } else {
+            polygon_convex(&self.convex_hull().points())
+        }
+    }
+}
+
+impl Collide for Circle {
+    fn as_convex(&self) -> Convex {
+        let center = self.center();
+        Convex::Circle {
+            center: (center.x as f32, center.y as f32),
+            radius: self.radius() as f32,
+        }
+    }
+}
+
+impl Collide for ShapeBox {
+    fn as_convex(&self) -> Convex {
+        match self {
+            ShapeBox::Circle(circle) => circle.as_convex(),
+            ShapeBox::Rect(rect) => rect.as_convex(),
+            ShapeBox::Polygon(polygon) => polygon.as_convex(),
+            shape => polygon_convex(&shape.convex_hull().points()),
+        }
+    }
+}
+
+/// Continuous collision detection, finds when a moving shape first touches another shape
+///
+/// Unlike checking the start and end positions this doesn't miss thin shapes (such as [Line]s)
+/// that the shape moves straight past
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+/// let ball = Circle::new((0, 0), 5);
+/// let wall = Line::new((50, -20), (50, 20));
+/// let delta = coord!(100, 0);
+/// // the end position is past the wall
+/// assert!(!ball.translate_by(delta).intersects_line(&wall));
+/// let time = ball.sweep(delta, &wall).unwrap();
+/// assert_eq!(time, 0.45);
+/// let moved = ball.translate_by(coord!(45, 0));
+/// assert_eq!(moved.distance_to(&wall), 0.0);
+/// ```
+pub trait Sweep: Shape {
+    /// Returns how far along `delta` (from 0.0 to 1.0) this shape can be moved before it touches `other`,
+    /// or None if it doesn't touch `other` at any point
+    ///
+    /// Shapes that already overlap return 0.0
+    #[must_use]
+    fn sweep(&self, delta: Coord, other: &dyn Shape) -> Option<f32>
+    where
+        Self: Sized,
+    {
+        time_of_impact(&self.to_shape_box(), delta, &other.to_shape_box())
+    }
+}
+
+impl Sweep for Circle {}
+
+impl Sweep for Rect {}
+
+/// Concave polygons work but are slower to check
+impl Sweep for Polygon {}
+
+const SWEEP_TOLERANCE: f32 = 0.01;
+/// Conservative advancement, the shape is moved forward by the distance between the shapes
+/// until they touch, this can never move past the other shape
+///
+/// Each step moves at least [SWEEP_TOLERANCE] so this ends within `speed / SWEEP_TOLERANCE` steps
+fn time_of_impact(shape: &ShapeBox, delta: Coord, other: &ShapeBox) -> Option<f32> {
+    let delta = fcoord(delta);
+    let speed = length(delta);
+    let mut time = 0.0;
+    loop {
+        let (a, b) = closest_points_moved(shape, scale(delta, time), other);
+        let distance = length(sub(b, a));
+        if distance <= SWEEP_TOLERANCE {
+            return Some(time);
+        }
+        if speed == 0.0 {
+            return None;
+        }
+        time += distance.max(SWEEP_TOLERANCE) / speed;
+        if time > 1.0 {
+            return None;
+        }
+    }
+}
+
+fn polygon_convex(points: &[Coord]) -> Convex {
+    Convex::Polygon(points.iter().map(|p| (p.x as f32, p.y as f32)).collect())
+}
+
+fn sat(first: &Convex, second: &Convex) -> Option<Manifold> {
+    if let (
+        Convex::Circle {
+            center: c1,
+            radius: r1,
+        },
+        Convex::Circle {
+            center: c2,
+            radius: r2,
+        },
+    ) = (first, second)
+    {
+        let diff = (c2.0 - c1.0, c2.1 - c1.1);
+        let dist = (diff.0 * diff.0 + diff.1 * diff.1).sqrt();
+        let depth = r1 + r2 - dist;
+        if depth < 0.0 {
+            return None;
+        }
+        let normal = if dist == 0.0 {
+            (1.0, 0.0)
+        } else {
+            (diff.0 / dist, diff.1 / dist)
+        };
+        return Some(Manifold { depth, normal });
+    }
+
+    let mut axes = vec![];
+    for (shape, other) in [(first, second), (second, first)] {
+        match shape {
+            Convex::Polygon(points) => axes.extend(edge_normals(points)),
+            Convex::Circle { center, .. } => {
+                if let Convex::Polygon(points) = other {
+                    if let Some(axis) = closest_vertex_axis(*center, points) {
+                        axes.push(axis);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut best: Option<Manifold> = None;
+    for axis in axes {
+        let (min1, max1) = project(first, axis);
+        let (min2, max2) = project(second, axis);
+        let forward = max1 - min2;
+        let backward = max2 - min1;
+        if forward < 0.0 || backward < 0.0 {
+            return None;
+        }
+        let manifold = if forward <= backward {
+            Manifold {
+                depth: forward,
+                normal: axis,
+            }
+        } else {
+            Manifold {
+                depth: backward,
+                normal: (-axis.0, -axis.1),
+            }
+        };
+        if best.map(|best| manifold.depth < best.depth).unwrap_or(true) {
+            best = Some(manifold);
+        }
+    }
+    best
+}
+
+fn normalise(vector: (f32, f32)) -> Option<(f32, f32)> {
+    let len = (vector.0 * vector.0 + vector.1 * vector.1).sqrt();
+    (len > 0.0).then(|| (vector.0 / len, vector.1 / len))
+}
+
+fn edge_normals(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    (0..points.len())
+        .filter_map(|i| {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            normalise((b.1 - a.1, a.0 - b.0))
+        })
+        .collect()
+}
+
+fn closest_vertex_axis(center: (f32, f32), points: &[(f32, f32)]) -> Option<(f32, f32)> {
+    let closest = points.iter().min_by(|a, b| {
+        let dist_a = (a.0 - center.0).powi(2) + (a.1 - center.1).powi(2);
+        let dist_b = (b.0 - center.0).powi(2) + (b.1 - center.1).powi(2);
+        dist_a.total_cmp(&dist_b)
+    })?;
+    normalise((closest.0 - center.0, closest.1 - center.1))
+}
+
+fn project(shape: &Convex, axis: (f32, f32)) -> (f32, f32) {
+    match shape {
+        Convex::Polygon(points) => points
+            .iter()
+            .map(|p| p.0 * axis.0 + p.1 * axis.1)
+            .fold((f32::MAX, f32::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            }),
+        Convex::Circle { center, radius } => {
+            let value = center.0 * axis.0 + center.1 * axis.1;
+            (value - radius, value + radius)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::shape_box::ShapeBox;
+    use crate::shape_group::ShapeGroup;
+
+    #[test]
+    fn rects() {
+        let first = Rect::new((0, 0), (10, 10));
+        let second = Rect::new((8, 2), (20, 8));
+        let manifold = first.collide(&second).unwrap();
+        assert_eq!(manifold.depth(), 2.0);
+        assert_eq!(manifold.normal(), (1.0, 0.0));
+        assert_eq!(manifold.mtv_coord(), coord!(-2, 0));
+        let moved = first.translate_by(manifold.mtv_coord());
+        assert_eq!(moved.collide(&second).unwrap().depth(), 0.0);
+        assert!(first.collide(&Rect::new((11, 0), (20, 10))).is_none());
+    }
+
+    #[test]
+    fn rect_inside_rect() {
+        let outer = Rect::new((10, 10), (40, 40));
+        let inner = Rect::new((20, 20), (30, 30));
+        assert!(!outer.intersects_rect(&inner));
+        let manifold = outer.collide(&inner).unwrap();
+        assert_eq!(manifold.depth(), 20.0);
+        let manifold = inner.collide(&outer).unwrap();
+        assert_eq!(manifold.depth(), 20.0);
+    }
+
+    #[test]
+    fn circles() {
+        let first = Circle::new((0, 0), 10);
+        let second = Circle::new((15, 0), 10);
+        let manifold = first.collide(&second).unwrap();
+        assert_eq!(manifold.depth(), 5.0);
+        assert_eq!(manifold.normal(), (1.0, 0.0));
+        assert_eq!(manifold.mtv(), (-5.0, -0.0));
+        assert!(first.collide(&Circle::new((25, 0), 4)).is_none());
+    }
+
+    #[test]
+    fn circle_and_polygon() {
+        let circle = Circle::new((0, 0), 10);
+        let rect = Rect::new((8, -5), (30, 5));
+        let manifold = circle.collide(&rect).unwrap();
+        assert_eq!(manifold.depth(), 2.0);
+        assert_eq!(manifold.normal(), (1.0, 0.0));
+        let manifold = rect.collide(&circle).unwrap();
+        assert_eq!(manifold.normal(), (-1.0, 0.0));
+
+        // close to the corner but not touching
+        let corner = Rect::new((8, 8), (20, 20));
+        assert!(circle.collide(&corner).is_none());
+        assert!(corner.collide(&circle).is_none());
+    }
+
+    #[test]
+    fn triangle_and_polygon() {
+        let triangle = Triangle::new((0, 0), (20, 0), (0, 20));
+        let square = Polygon::new(&[(8, 8), (20, 8), (20, 20), (8, 20)]);
+        let manifold = triangle.collide(&square).unwrap();
+        let (nx, ny) = manifold.normal();
+        assert!((nx - ny).abs() < 0.001);
+        assert!(nx > 0.0);
+        assert!((manifold.depth() - 2.828).abs() < 0.01);
+        assert!(triangle
+            .collide(&square.translate_by(coord!(5, 5)))
+            .is_none());
+    }
+
+    #[test]
+    fn sweep_circle() {
+        let ball = Circle::new((0, 0), 5);
+        let wall = Line::new((50, -20), (50, 20));
+        assert_eq!(ball.sweep(coord!(100, 0), &wall), Some(0.45));
+        assert_eq!(ball.sweep(coord!(-100, 0), &wall), None);
+        assert_eq!(ball.sweep(coord!(40, 0), &wall), None);
+        assert_eq!(ball.sweep(coord!(0, 100), &wall), None);
+        assert_eq!(
+            ball.sweep(coord!(100, 0), &Circle::new((0, 2), 3)),
+            Some(0.0)
+        );
+
+        let target = Circle::new((40, 30), 5);
+        let time = ball.sweep(coord!(80, 60), &target).unwrap();
+        assert!((time - 0.4).abs() < 0.001);
+    }
+
+    #[test]
+    fn sweep_rect() {
+        let rect = Rect::new((0, 0), (10, 10));
+        let wall = Rect::new((30, -50), (32, 50));
+        assert_eq!(rect.sweep(coord!(40, 0), &wall), Some(0.5));
+        let time = rect.sweep(coord!(40, 40), &wall).unwrap();
+        assert!((time - 0.5).abs() < 0.001);
+        // passes above
+        assert_eq!(rect.sweep(coord!(40, 0), &Circle::new((30, -20), 5)), None);
+        // moving past a corner
+        let corner = Triangle::new((20, 20), (40, 20), (40, 40));
+        assert_eq!(rect.sweep(coord!(40, 0), &corner), None);
+        let time = rect.sweep(coord!(20, 20), &corner).unwrap();
+        assert!((time - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn sweep_polygon() {
+        let polygon = Polygon::regular((0, 0), 10, 6, 0);
+        let annulus = Annulus::new((0, 0), 20, 30);
+        let time = polygon.sweep(coord!(0, 100), &annulus).unwrap();
+        assert!((time - 0.1).abs() < 0.001);
+        let wall = Line::new((-20, 50), (20, 50));
+        let time = polygon.sweep(coord!(0, 100), &wall).unwrap();
+        assert!((time - 0.4).abs() < 0.001);
+    }
+
+    #[test]
+    fn sweep_glancing() {
+        let line = Line::new((0, 20), (2000, 20));
+        let ball = Circle::new((0, 0), 5);
+        let time = ball.sweep(coord!(1000, 50), &line).unwrap();
+        assert!((time - 0.3).abs() < 0.001, "{time}");
+
+        let hexagon = Polygon::regular((0, 0), 5, 6, 0);
+        let bottom = hexagon.bottom() as f32;
+        let time = hexagon.sweep(coord!(1000, 40), &line).unwrap();
+        assert!((time - (20.0 - bottom) / 40.0).abs() < 0.001, "{time}");
+
+        // end position is still short of the line
+        assert_eq!(ball.sweep(coord!(1000, 14), &line), None);
+    }
+
+    #[test]
+    fn sweep_along_rail() {
+        let ball = Circle::new((0, 0), 5);
+        let wall = Line::new((550, -20), (550, 20));
+        let rail = ShapeGroup::new(&[
+            ShapeBox::from(Line::new((-10, 6), (10000, 6))),
+            ShapeBox::from(wall.clone()),
+        ]);
+        let expected = ball.sweep(coord!(10000, 0), &wall).unwrap();
+        let time = ball.sweep(coord!(10000, 0), &rail).unwrap();
+        assert!((time - expected).abs() < 0.0001, "{time} {expected}");
+    }
+
+    #[test]
+    fn shape_box() {
+        let first = ShapeBox::from(Circle::new((0, 0), 10));
+        let second = ShapeBox::from(Triangle::new((5, 0), (30, -10), (30, 10)));
+        assert!(first.collide(&second).is_some());
+        assert!(first.collide(&Circle::new((0, 0), 2)).is_some());
+    }
+}
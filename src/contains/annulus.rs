@@ -0,0 +1,41 @@
+use crate::contains::outside_region;
+use crate::prelude::*;
+
+impl ContainsShape for Annulus {
+    fn contains_rect(&self, rect: &Rect) -> bool {
+        self.as_outer_circle().contains_rect(rect) && outside_hole(self, rect)
+    }
+
+    fn contains_circle(&self, circle: &Circle) -> bool {
+        self.as_outer_circle().contains_circle(circle) && outside_hole(self, circle)
+    }
+
+    fn contains_line(&self, line: &Line) -> bool {
+        self.as_outer_circle().contains_line(line) && outside_hole(self, line)
+    }
+
+    fn contains_triangle(&self, triangle: &Triangle) -> bool {
+        self.as_outer_circle().contains_triangle(triangle) && outside_hole(self, triangle)
+    }
+
+    fn contains_ellipse(&self, ellipse: &Ellipse) -> bool {
+        self.as_outer_circle().contains_ellipse(ellipse) && outside_hole(self, ellipse)
+    }
+
+    fn contains_polygon(&self, polygon: &Polygon) -> bool {
+        self.as_outer_circle().contains_polygon(polygon) && outside_hole(self, polygon)
+    }
+
+    fn contains_annulus(&self, annulus: &Annulus) -> bool {
+        self.as_outer_circle().contains_annulus(annulus) && outside_hole(self, annulus)
+    }
+}
+
+/// Returns true if `shape` doesn't touch, cover or sit inside the hole of `annulus`
+fn outside_hole<S: Shape + IntersectsShape>(annulus: &Annulus, shape: &S) -> bool {
+    if annulus.inner_radius() == 0 {
+        return true;
+    }
+    let hole = annulus.as_inner_circle();
+    !shape.intersects_circle(&hole) && outside_region(&hole, shape)
+}
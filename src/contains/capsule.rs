@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+impl ContainsShape for Capsule {
+    fn contains_rect(&self, rect: &Rect) -> bool {
+        self.contains_polygon(&rect.as_polygon())
+    }
+
+    fn contains_circle(&self, circle: &Circle) -> bool {
+        self.distance_from_spine(circle.center()) + circle.radius() <= self.radius()
+    }
+
+    fn contains_ellipse(&self, ellipse: &Ellipse) -> bool {
+        self.contains_polygon(&ellipse.as_polygon())
+    }
+
+    fn contains_annulus(&self, annulus: &Annulus) -> bool {
+        self.contains_circle(&annulus.as_outer_circle())
+    }
+
+    fn contains_capsule(&self, capsule: &Capsule) -> bool {
+        self.distance_from_spine(capsule.start()) + capsule.radius() <= self.radius()
+            && self.distance_from_spine(capsule.end()) + capsule.radius() <= self.radius()
+    }
+}
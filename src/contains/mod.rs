@@ -1,7 +1,10 @@
+pub mod annulus;
+pub mod capsule;
 pub mod circle;
 pub mod ellipse;
 pub mod line;
 pub mod polygon;
+pub mod polygon_with_holes;
 pub mod rect;
 pub mod triangle;
 
@@ -62,6 +65,33 @@ pub trait ContainsShape {
     {
         contains_points(self, polygon)
     }
+
+    /// Returns true if `self` contains `annulus`
+    #[must_use]
+    fn contains_annulus(&self, annulus: &Annulus) -> bool
+    where
+        Self: Shape + Sized,
+    {
+        self.contains_circle(&annulus.as_outer_circle())
+    }
+
+    /// Returns true if `self` contains `capsule`
+    #[must_use]
+    fn contains_capsule(&self, capsule: &Capsule) -> bool
+    where
+        Self: Shape + Sized,
+    {
+        self.contains_polygon(&capsule.as_polygon())
+    }
+
+    /// Returns true if `self` contains `polygon`
+    #[must_use]
+    fn contains_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool
+    where
+        Self: Shape + Sized,
+    {
+        self.contains_polygon(polygon.outer())
+    }
 }
 
 #[inline]
@@ -73,3 +103,16 @@ fn contains_points(shape: &dyn Shape, other: &dyn Shape) -> bool {
     }
     true
 }
+
+/// Returns true if neither `hole` nor `shape` have any outline pixels inside the other,
+/// this means they are separate unless one crosses the other (which must be checked separately)
+fn outside_region(hole: &dyn Shape, shape: &dyn Shape) -> bool {
+    !hole
+        .outline_pixels()
+        .into_iter()
+        .any(|point| shape.contains(point))
+        && !shape
+            .outline_pixels()
+            .into_iter()
+            .any(|point| hole.contains(point))
+}
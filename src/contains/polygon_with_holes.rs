@@ -0,0 +1,48 @@
+use crate::contains::outside_region;
+use crate::prelude::*;
+
+impl ContainsShape for PolygonWithHoles {
+    fn contains_rect(&self, rect: &Rect) -> bool {
+        self.outer().contains_rect(rect) && outside_holes(self, rect)
+    }
+
+    fn contains_circle(&self, circle: &Circle) -> bool {
+        self.outer().contains_circle(circle) && outside_holes(self, circle)
+    }
+
+    fn contains_line(&self, line: &Line) -> bool {
+        self.outer().contains_line(line) && outside_holes(self, line)
+    }
+
+    fn contains_triangle(&self, triangle: &Triangle) -> bool {
+        self.outer().contains_triangle(triangle) && outside_holes(self, triangle)
+    }
+
+    fn contains_ellipse(&self, ellipse: &Ellipse) -> bool {
+        self.outer().contains_ellipse(ellipse) && outside_holes(self, ellipse)
+    }
+
+    fn contains_polygon(&self, polygon: &Polygon) -> bool {
+        self.outer().contains_polygon(polygon) && outside_holes(self, polygon)
+    }
+
+    fn contains_annulus(&self, annulus: &Annulus) -> bool {
+        self.outer().contains_annulus(annulus) && outside_holes(self, annulus)
+    }
+
+    fn contains_capsule(&self, capsule: &Capsule) -> bool {
+        self.outer().contains_capsule(capsule) && outside_holes(self, capsule)
+    }
+
+    fn contains_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        self.outer().contains_polygon_with_holes(polygon) && outside_holes(self, polygon)
+    }
+}
+
+/// Returns true if `shape` doesn't touch, cover or sit inside any hole of `polygon`
+fn outside_holes<S: Shape + IntersectsShape>(polygon: &PolygonWithHoles, shape: &S) -> bool {
+    polygon
+        .holes()
+        .iter()
+        .all(|hole| !shape.intersects_polygon(hole) && outside_region(hole, shape))
+}
@@ -2,9 +2,10 @@ use crate::prelude::*;
 
 impl ContainsShape for Rect {
     fn contains_circle(&self, circle: &Circle) -> bool {
-        let dist = self.center().distance(circle.center()) - circle.radius() / 2;
-        let max = self.width().max(self.height()) / 2;
-        dist < max
+        self.left() <= circle.left()
+            && circle.right() <= self.right()
+            && self.top() <= circle.top()
+            && circle.bottom() <= self.bottom()
     }
 
     // fn contains_ellipse(&self, ellipse: &Ellipse) -> bool {
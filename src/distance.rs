@@ -0,0 +1,617 @@
+//! Shortest distances between shapes, see [Shape::distance_to] and [Shape::closest_points]
+//!
+//! Convex shapes use GJK (with circles and capsules treated as a point or line plus a radius),
+//! everything else compares each part of both outlines
+
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+
+pub(crate) type Point = (f32, f32);
+
+const GJK_ITERATIONS: usize = 64;
+
+/// Part of a shape outline
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Part {
+    Segment(Point, Point),
+    Circle(Point, f32),
+}
+
+/// Distance from `point` to the outline of `shape`, negative if `point` is inside `shape`
+///
+/// Lines don't have an inside so are never negative
+pub(crate) fn distance_to_point(shape: &ShapeBox, point: Coord) -> f32 {
+    let fpoint = fcoord(point);
+    let nearest = nearest_outline_point(shape, fpoint);
+    let distance = length(sub(nearest, fpoint));
+    if !matches!(shape, ShapeBox::Line(_)) && distance > 0.0 && shape.contains(point) {
+        -distance
+    } else {
+        distance
+    }
+}
+
+/// Closest point on `first` and closest point on `second`, these are the same if the shapes overlap
+pub(crate) fn closest_points(first: &ShapeBox, second: &ShapeBox) -> (Point, Point) {
+    closest_points_moved(first, (0.0, 0.0), second)
+}
+
+/// Same as [closest_points] but with `first` moved by `offset`, which may be fractional
+pub(crate) fn closest_points_moved(
+    first: &ShapeBox,
+    offset: Point,
+    second: &ShapeBox,
+) -> (Point, Point) {
+    if let ShapeBox::Group(group) = first {
+        return closest_of(
+            group
+                .shapes()
+                .iter()
+                .map(|shape| closest_points_moved(shape, offset, second)),
+        );
+    }
+    if let ShapeBox::Group(group) = second {
+        return closest_of(
+            group
+                .shapes()
+                .iter()
+                .map(|shape| closest_points_moved(first, offset, shape)),
+        );
+    }
+    if let (Some(mut a), Some(b)) = (convex(first), convex(second)) {
+        a.points
+            .iter_mut()
+            .for_each(|point| *point = add(*point, offset));
+        return gjk(&a, &b);
+    }
+    let first_parts: Vec<Part> = parts(first)
+        .into_iter()
+        .map(|part| move_part(part, offset))
+        .collect();
+    let second_parts = parts(second);
+    let closest = closest_of(
+        first_parts
+            .iter()
+            .flat_map(|a| second_parts.iter().map(move |b| closest_parts(*a, *b))),
+    );
+    if closest.0 != closest.1 {
+        // outlines don't touch but one shape may be inside the other
+        if let Some(point) = second_parts.first().map(part_start) {
+            if first.contains(round(sub(point, offset))) {
+                return (point, point);
+            }
+        }
+        if let Some(point) = first_parts.first().map(part_start) {
+            if second.contains(round(point)) {
+                return (point, point);
+            }
+        }
+    }
+    closest
+}
+
+fn move_part(part: Part, offset: Point) -> Part {
+    match part {
+        Part::Segment(start, end) => Part::Segment(add(start, offset), add(end, offset)),
+        Part::Circle(center, radius) => Part::Circle(add(center, offset), radius),
+    }
+}
+
+fn closest_of<I: Iterator<Item = (Point, Point)>>(pairs: I) -> (Point, Point) {
+    pairs
+        .min_by(|a, b| length(sub(a.1, a.0)).total_cmp(&length(sub(b.1, b.0))))
+        .unwrap_or_default()
+}
+
+fn nearest_outline_point(shape: &ShapeBox, point: Point) -> Point {
+    if let ShapeBox::Group(group) = shape {
+        return group
+            .shapes()
+            .iter()
+            .map(|shape| nearest_outline_point(shape, point))
+            .min_by(|a, b| length(sub(*a, point)).total_cmp(&length(sub(*b, point))))
+            .unwrap_or(point);
+    }
+    parts(shape)
+        .into_iter()
+        .map(|part| match part {
+            Part::Segment(start, end) => nearest_on_segment(point, start, end),
+            Part::Circle(center, radius) => nearest_on_circle(point, center, radius),
+        })
+        .min_by(|a, b| length(sub(*a, point)).total_cmp(&length(sub(*b, point))))
+        .unwrap_or(point)
+}
+
+/// Outline of the shape, curves other than circles are approximated
+pub(crate) fn parts(shape: &ShapeBox) -> Vec<Part> {
+    match shape {
+        ShapeBox::Line(line) => vec![Part::Segment(fcoord(line.start()), fcoord(line.end()))],
+        ShapeBox::Rect(rect) => ring(&rect.as_polygon().points()),
+        ShapeBox::Triangle(triangle) => ring(&triangle.points()),
+        ShapeBox::Circle(circle) => vec![Part::Circle(
+            fcoord(circle.center()),
+            circle.radius() as f32,
+        )],
+        ShapeBox::Ellipse(ellipse) => ring(&ellipse.as_polygon().points()),
+        ShapeBox::Polygon(polygon) => ring(&polygon.points()),
+        ShapeBox::Annulus(annulus) => vec![
+            Part::Circle(fcoord(annulus.center()), annulus.outer_radius() as f32),
+            Part::Circle(fcoord(annulus.center()), annulus.inner_radius() as f32),
+        ],
+        ShapeBox::Capsule(capsule) => ring(&capsule.as_polygon().points()),
+        ShapeBox::PolygonWithHoles(polygon) => polygon
+            .as_lines()
+            .iter()
+            .map(|line| Part::Segment(fcoord(line.start()), fcoord(line.end())))
+            .collect(),
+        ShapeBox::Group(group) => group.shapes().iter().flat_map(parts).collect(),
+    }
+}
+
+fn ring(points: &[Coord]) -> Vec<Part> {
+    (0..points.len())
+        .map(|i| Part::Segment(fcoord(points[i]), fcoord(points[(i + 1) % points.len()])))
+        .collect()
+}
+
+fn part_start(part: &Part) -> Point {
+    match part {
+        Part::Segment(start, _) => *start,
+        Part::Circle(center, radius) => (center.0 + radius, center.1),
+    }
+}
+
+/// Closest points between two parts of outlines
+fn closest_parts(first: Part, second: Part) -> (Point, Point) {
+    match (first, second) {
+        (Part::Segment(a1, a2), Part::Segment(b1, b2)) => closest_segments(a1, a2, b1, b2),
+        (Part::Segment(start, end), Part::Circle(center, radius)) => {
+            let (b, a) = closest_circle_segment(center, radius, start, end);
+            (a, b)
+        }
+        (Part::Circle(center, radius), Part::Segment(start, end)) => {
+            closest_circle_segment(center, radius, start, end)
+        }
+        (Part::Circle(c1, r1), Part::Circle(c2, r2)) => closest_circles(c1, r1, c2, r2),
+    }
+}
+
+fn closest_segments(a1: Point, a2: Point, b1: Point, b2: Point) -> (Point, Point) {
+    let r = sub(a2, a1);
+    let s = sub(b2, b1);
+    let denom = cross(r, s);
+    if denom != 0.0 {
+        let diff = sub(b1, a1);
+        let t = cross(diff, s) / denom;
+        let u = cross(diff, r) / denom;
+        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            let point = add(a1, scale(r, t));
+            return (point, point);
+        }
+    }
+    closest_of(
+        [
+            (a1, nearest_on_segment(a1, b1, b2)),
+            (a2, nearest_on_segment(a2, b1, b2)),
+            (nearest_on_segment(b1, a1, a2), b1),
+            (nearest_on_segment(b2, a1, a2), b2),
+        ]
+        .into_iter(),
+    )
+}
+
+/// Closest point on a circle outline and closest point on a line
+fn closest_circle_segment(center: Point, radius: f32, start: Point, end: Point) -> (Point, Point) {
+    let nearest = nearest_on_segment(center, start, end);
+    let nearest_dist = length(sub(nearest, center));
+    if nearest_dist >= radius {
+        return (nearest_on_circle(nearest, center, radius), nearest);
+    }
+    let farthest = if length(sub(start, center)) >= length(sub(end, center)) {
+        start
+    } else {
+        end
+    };
+    if length(sub(farthest, center)) <= radius {
+        // line is inside the circle
+        return (nearest_on_circle(farthest, center, radius), farthest);
+    }
+    // line crosses the circle between `nearest` and `farthest`
+    let along = (radius * radius - nearest_dist * nearest_dist).sqrt();
+    let point = add(nearest, scale(normalise(sub(farthest, nearest)), along));
+    (point, point)
+}
+
+fn closest_circles(c1: Point, r1: f32, c2: Point, r2: f32) -> (Point, Point) {
+    let diff = sub(c2, c1);
+    let dist = length(diff);
+    let dir = if dist == 0.0 {
+        (1.0, 0.0)
+    } else {
+        scale(diff, 1.0 / dist)
+    };
+    if dist >= r1 + r2 {
+        (add(c1, scale(dir, r1)), sub(c2, scale(dir, r2)))
+    } else if dist <= (r1 - r2).abs() {
+        // one circle is inside the other
+        if r1 >= r2 {
+            (add(c1, scale(dir, r1)), add(c2, scale(dir, r2)))
+        } else {
+            (sub(c1, scale(dir, r1)), sub(c2, scale(dir, r2)))
+        }
+    } else {
+        let along = (dist * dist + r1 * r1 - r2 * r2) / (2.0 * dist);
+        let side = (r1 * r1 - along * along).max(0.0).sqrt();
+        let point = add(add(c1, scale(dir, along)), scale((-dir.1, dir.0), side));
+        (point, point)
+    }
+}
+
+fn nearest_on_segment(point: Point, start: Point, end: Point) -> Point {
+    let dir = sub(end, start);
+    let len_sq = dot(dir, dir);
+    if len_sq == 0.0 {
+        return start;
+    }
+    let t = (dot(sub(point, start), dir) / len_sq).clamp(0.0, 1.0);
+    add(start, scale(dir, t))
+}
+
+fn nearest_on_circle(point: Point, center: Point, radius: f32) -> Point {
+    let diff = sub(point, center);
+    if length(diff) == 0.0 {
+        return (center.0 + radius, center.1);
+    }
+    add(center, scale(normalise(diff), radius))
+}
+
+/// Convex shape as the corners of its core plus a radius
+struct ConvexCore {
+    points: Vec<Point>,
+    radius: f32,
+}
+
+impl ConvexCore {
+    fn support(&self, dir: Point) -> Point {
+        self.points
+            .iter()
+            .copied()
+            .max_by(|a, b| dot(*a, dir).total_cmp(&dot(*b, dir)))
+            .unwrap_or_default()
+    }
+}
+
+fn convex(shape: &ShapeBox) -> Option<ConvexCore> {
+    let polygon = |points: Vec<Coord>| ConvexCore {
+        points: points.into_iter().map(fcoord).collect(),
+        radius: 0.0,
+    };
+    match shape {
+        ShapeBox::Line(line) => Some(polygon(line.points())),
+        ShapeBox::Rect(rect) => Some(polygon(rect.as_polygon().points())),
+        ShapeBox::Triangle(triangle) => Some(polygon(triangle.points())),
+        ShapeBox::Circle(circle) => Some(ConvexCore {
+            points: vec![fcoord(circle.center())],
+            radius: circle.radius() as f32,
+        }),
+        ShapeBox::Ellipse(ellipse) => Some(polygon(ellipse.as_polygon().points())),
+        ShapeBox::Polygon(polygon_shape) if polygon_shape.is_convex() => {
+            Some(polygon(polygon_shape.points()))
+        }
+        ShapeBox::Capsule(capsule) => Some(ConvexCore {
+            points: vec![fcoord(capsule.start()), fcoord(capsule.end())],
+            radius: capsule.radius() as f32,
+        }),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SimplexPoint {
+    first: Point,
+    second: Point,
+    diff: Point,
+}
+
+/// GJK distance between the cores, then the radii are removed
+fn gjk(first: &ConvexCore, second: &ConvexCore) -> (Point, Point) {
+    let (a, b) = gjk_cores(first, second);
+    let diff = sub(b, a);
+    let dist = length(diff);
+    let radii = first.radius + second.radius;
+    if dist > radii {
+        let dir = scale(diff, 1.0 / dist);
+        (
+            add(a, scale(dir, first.radius)),
+            sub(b, scale(dir, second.radius)),
+        )
+    } else {
+        let point = if dist == 0.0 {
+            a
+        } else {
+            add(a, scale(diff, first.radius / radii))
+        };
+        (point, point)
+    }
+}
+
+fn gjk_cores(first: &ConvexCore, second: &ConvexCore) -> (Point, Point) {
+    let support = |dir: Point| {
+        let first = first.support(dir);
+        let second = second.support(scale(dir, -1.0));
+        SimplexPoint {
+            first,
+            second,
+            diff: sub(first, second),
+        }
+    };
+    let mut simplex = vec![support((1.0, 0.0))];
+    let mut weights = vec![1.0];
+    let mut closest = simplex[0].diff;
+    for _ in 0..GJK_ITERATIONS {
+        let len_sq = dot(closest, closest);
+        if len_sq <= f32::EPSILON {
+            break;
+        }
+        let next = support(scale(closest, -1.0));
+        if len_sq - dot(closest, next.diff) <= len_sq * 1e-6
+            || simplex.iter().any(|point| point.diff == next.diff)
+        {
+            break;
+        }
+        simplex.push(next);
+        let (reduced, new_weights) = reduce_simplex(&simplex);
+        if reduced.len() == 3 {
+            // origin is inside, so the shapes overlap
+            simplex = reduced;
+            weights = new_weights;
+            break;
+        }
+        simplex = reduced;
+        weights = new_weights;
+        closest = simplex
+            .iter()
+            .zip(&weights)
+            .fold((0.0, 0.0), |acc, (point, weight)| {
+                add(acc, scale(point.diff, *weight))
+            });
+    }
+    let first = simplex
+        .iter()
+        .zip(&weights)
+        .fold((0.0, 0.0), |acc, (point, weight)| {
+            add(acc, scale(point.first, *weight))
+        });
+    if simplex.len() == 3 {
+        return (first, first);
+    }
+    let second = simplex
+        .iter()
+        .zip(&weights)
+        .fold((0.0, 0.0), |acc, (point, weight)| {
+            add(acc, scale(point.second, *weight))
+        });
+    (first, second)
+}
+
+/// Smallest part of the simplex closest to the origin with the weight of each point
+fn reduce_simplex(simplex: &[SimplexPoint]) -> (Vec<SimplexPoint>, Vec<f32>) {
+    match simplex {
+        [a, b] => {
+            let dir = sub(b.diff, a.diff);
+            let len_sq = dot(dir, dir);
+            let t = if len_sq == 0.0 {
+                0.0
+            } else {
+                (-dot(a.diff, dir) / len_sq).clamp(0.0, 1.0)
+            };
+            if t == 0.0 {
+                (vec![*a], vec![1.0])
+            } else if t == 1.0 {
+                (vec![*b], vec![1.0])
+            } else {
+                (vec![*a, *b], vec![1.0 - t, t])
+            }
+        }
+        [a, b, c] => {
+            let area = cross(sub(b.diff, a.diff), sub(c.diff, a.diff));
+            if area != 0.0 {
+                let wa = cross(b.diff, c.diff) / area;
+                let wb = cross(c.diff, a.diff) / area;
+                let wc = cross(a.diff, b.diff) / area;
+                if wa >= 0.0 && wb >= 0.0 && wc >= 0.0 {
+                    return (vec![*a, *b, *c], vec![wa, wb, wc]);
+                }
+            }
+            [[*a, *b], [*b, *c], [*a, *c]]
+                .iter()
+                .map(|edge| reduce_simplex(edge))
+                .min_by(|x, y| {
+                    let len = |(points, weights): &(Vec<SimplexPoint>, Vec<f32>)| {
+                        length(
+                            points
+                                .iter()
+                                .zip(weights)
+                                .fold((0.0, 0.0), |acc, (point, weight)| {
+                                    add(acc, scale(point.diff, *weight))
+                                }),
+                        )
+                    };
+                    len(x).total_cmp(&len(y))
+                })
+                .unwrap_or_default()
+        }
+        _ => (simplex.to_vec(), vec![1.0; simplex.len()]),
+    }
+}
+
+#[inline]
+pub(crate) fn fcoord(point: Coord) -> Point {
+    (point.x as f32, point.y as f32)
+}
+
+#[inline]
+pub(crate) fn round(point: Point) -> Coord {
+    coord!(point.0.round(), point.1.round())
+}
+
+#[inline]
+pub(crate) fn add(a: Point, b: Point) -> Point {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+#[inline]
+pub(crate) fn sub(a: Point, b: Point) -> Point {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+#[inline]
+pub(crate) fn scale(a: Point, factor: f32) -> Point {
+    (a.0 * factor, a.1 * factor)
+}
+
+#[inline]
+pub(crate) fn dot(a: Point, b: Point) -> f32 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+#[inline]
+pub(crate) fn cross(a: Point, b: Point) -> f32 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+#[inline]
+pub(crate) fn length(a: Point) -> f32 {
+    dot(a, a).sqrt()
+}
+
+pub(crate) fn normalise(a: Point) -> Point {
+    let len = length(a);
+    if len == 0.0 {
+        a
+    } else {
+        scale(a, 1.0 / len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::shape_box::ShapeBox;
+    use crate::shape_group::ShapeGroup;
+
+    #[test]
+    fn point_distance() {
+        let rect = Rect::new((0, 0), (10, 10));
+        assert_eq!(rect.distance_to_point(coord!(15, 5)), 5.0);
+        assert_eq!(rect.distance_to_point(coord!(3, 5)), -3.0);
+        assert_eq!(rect.distance_to_point(coord!(13, 14)), 5.0);
+        assert_eq!(rect.distance_to_point(coord!(10, 5)), 0.0);
+
+        let circle = Circle::new((0, 0), 10);
+        assert_eq!(circle.distance_to_point(coord!(0, 15)), 5.0);
+        assert_eq!(circle.distance_to_point(coord!(0, 4)), -6.0);
+
+        let line = Line::new((0, 0), (10, 0));
+        assert_eq!(line.distance_to_point(coord!(5, -3)), 3.0);
+        assert_eq!(line.distance_to_point(coord!(14, 3)), 5.0);
+
+        let annulus = Annulus::new((0, 0), 5, 10);
+        assert_eq!(annulus.distance_to_point(coord!(0, 0)), 5.0);
+        assert_eq!(annulus.distance_to_point(coord!(7, 0)), -2.0);
+    }
+
+    #[test]
+    fn convex_shapes() {
+        let first = Rect::new((0, 0), (10, 10));
+        let second = Rect::new((20, 5), (30, 30));
+        assert_eq!(first.distance_to(&second), 10.0);
+        let (a, b) = first.closest_points(&second);
+        assert_eq!(a.x, 10);
+        assert_eq!(b.x, 20);
+        assert_eq!(a.y, b.y);
+
+        let triangle = Triangle::new((20, 20), (30, 20), (20, 30));
+        assert!((first.distance_to(&triangle) - 14.142).abs() < 0.01);
+        assert_eq!(
+            first.closest_points(&triangle),
+            (coord!(10, 10), coord!(20, 20))
+        );
+
+        assert_eq!(first.distance_to(&Rect::new((5, 5), (15, 15))), 0.0);
+        assert_eq!(first.distance_to(&Rect::new((2, 2), (4, 4))), 0.0);
+    }
+
+    #[test]
+    fn circles() {
+        let circle = Circle::new((0, 0), 10);
+        assert_eq!(circle.distance_to(&Circle::new((30, 0), 5)), 15.0);
+        assert_eq!(
+            circle.closest_points(&Circle::new((0, 30), 5)),
+            (coord!(0, 10), coord!(0, 25))
+        );
+        assert_eq!(circle.distance_to(&Rect::new((20, -5), (30, 5))), 10.0);
+        assert_eq!(
+            circle.closest_points(&Line::new((-10, 20), (10, 20))),
+            (coord!(0, 10), coord!(0, 20))
+        );
+        assert_eq!(circle.distance_to(&Circle::new((5, 0), 10)), 0.0);
+        let capsule = Capsule::new((-20, 20), (20, 20), 4);
+        assert_eq!(circle.distance_to(&capsule), 6.0);
+    }
+
+    #[test]
+    fn lines() {
+        let first = Line::new((0, 0), (10, 0));
+        assert_eq!(first.distance_to(&Line::new((5, 5), (5, 20))), 5.0);
+        assert_eq!(first.distance_to(&Line::new((5, -5), (5, 20))), 0.0);
+        assert_eq!(
+            first.closest_points(&Line::new((20, -5), (20, 5))),
+            (coord!(10, 0), coord!(20, 0))
+        );
+    }
+
+    #[test]
+    fn concave_shapes() {
+        let u_shape = Polygon::new(&[
+            (0, 0),
+            (10, 0),
+            (10, 30),
+            (20, 30),
+            (20, 0),
+            (30, 0),
+            (30, 40),
+            (0, 40),
+        ]);
+        // inside the gap of the U
+        let rect = Rect::new((13, 5), (17, 10));
+        assert_eq!(u_shape.distance_to(&rect), 3.0);
+        assert_eq!(rect.distance_to(&u_shape), 3.0);
+        assert_eq!(u_shape.distance_to(&Rect::new((13, 5), (17, 35))), 0.0);
+
+        let annulus = Annulus::new((0, 0), 20, 30);
+        assert_eq!(annulus.distance_to(&Circle::new((0, 0), 5)), 15.0);
+        assert_eq!(
+            annulus.distance_to(&Rect::new((-5, -5), (5, 5))).round(),
+            13.0
+        );
+        assert_eq!(annulus.distance_to(&Circle::new((40, 0), 5)), 5.0);
+        assert_eq!(annulus.distance_to(&Circle::new((25, 0), 2)), 0.0);
+    }
+
+    #[test]
+    fn groups() {
+        let group = ShapeGroup::new(&[
+            ShapeBox::from(Rect::new((0, 0), (10, 10))),
+            ShapeBox::from(Circle::new((50, 5), 5)),
+        ]);
+        let point = Circle::new((30, 5), 1);
+        assert_eq!(group.distance_to(&point), 14.0);
+        assert_eq!(point.distance_to(&group), 14.0);
+        assert_eq!(group.distance_to_point(coord!(50, 20)), 10.0);
+        assert_eq!(
+            ShapeBox::from(group).closest_points(&point),
+            (coord!(45, 5), coord!(31, 5))
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Grid of [signed distances](Shape::signed_distance) for every pixel in a region
+///
+/// Used to render effects such as glows, soft shadows and outlines from a shape
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+/// let circle = Circle::new((10, 10), 5);
+/// let field = DistanceField::new(&circle, &Rect::new((0, 0), (20, 20)));
+/// assert_eq!(field.width(), 21);
+/// assert_eq!(field.get(coord!(10, 10)), Some(-5.0));
+/// assert_eq!(field.get(coord!(20, 10)), Some(5.0));
+/// assert_eq!(field.sample(10.0, 17.5), 2.5);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceField {
+    top_left: Coord,
+    width: usize,
+    height: usize,
+    values: Vec<f32>,
+}
+
+impl DistanceField {
+    /// Calculate the distance to `shape` for every pixel in `region` (including the edges)
+    #[must_use]
+    pub fn new(shape: &dyn Shape, region: &Rect) -> Self {
+        let top_left = coord!(region.left(), region.top());
+        let width = region.width() + 1;
+        let height = region.height() + 1;
+        let mut values = Vec::with_capacity(width * height);
+        for y in region.top()..=region.bottom() {
+            for x in region.left()..=region.right() {
+                values.push(shape.signed_distance(coord!(x, y)));
+            }
+        }
+        Self {
+            top_left,
+            width,
+            height,
+            values,
+        }
+    }
+}
+
+impl DistanceField {
+    #[inline]
+    #[must_use]
+    pub fn top_left(&self) -> Coord {
+        self.top_left
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Distances in rows from the top left
+    #[inline]
+    #[must_use]
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Distance at `point`, None if outside the region
+    #[must_use]
+    pub fn get(&self, point: Coord) -> Option<f32> {
+        let x = usize::try_from(point.x - self.top_left.x).ok()?;
+        let y = usize::try_from(point.y - self.top_left.y).ok()?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.values.get(y * self.width + x).copied()
+    }
+
+    /// Distance at `x`,`y` blended from the surrounding pixels
+    ///
+    /// Positions outside the region use the closest edge
+    #[must_use]
+    pub fn sample(&self, x: f32, y: f32) -> f32 {
+        if self.values.is_empty() {
+            return f32::MAX;
+        }
+        let x = (x - self.top_left.x as f32).clamp(0.0, (self.width - 1) as f32);
+        let y = (y - self.top_left.y as f32).clamp(0.0, (self.height - 1) as f32);
+        let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(self.height - 1));
+        let (tx, ty) = (x - x0 as f32, y - y0 as f32);
+        let value = |x: usize, y: usize| self.values[y * self.width + x];
+        let top = value(x0, y0) + (value(x1, y0) - value(x0, y0)) * tx;
+        let bottom = value(x0, y1) + (value(x1, y1) - value(x0, y1)) * tx;
+        top + (bottom - top) * ty
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::shape_box::ShapeBox;
+
+    fn check(shape: &dyn Shape, point: (isize, isize), expected: f32) {
+        let actual = shape.signed_distance(coord!(point.0, point.1));
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "{point:?} expected {expected} got {actual}"
+        );
+    }
+
+    #[test]
+    fn rect() {
+        let rect = Rect::new((0, 0), (10, 20));
+        check(&rect, (5, 10), -5.0);
+        check(&rect, (2, 10), -2.0);
+        check(&rect, (10, 10), 0.0);
+        check(&rect, (15, 10), 5.0);
+        check(&rect, (13, 24), 5.0);
+        check(&Rect::new((10, 20), (0, 0)), (13, 24), 5.0);
+    }
+
+    #[test]
+    fn circle_and_line() {
+        let circle = Circle::new((0, 0), 10);
+        check(&circle, (0, 0), -10.0);
+        check(&circle, (3, 4), -5.0);
+        check(&circle, (0, 20), 10.0);
+        let line = Line::new((0, 0), (10, 0));
+        check(&line, (5, 0), 0.0);
+        check(&line, (5, -3), 3.0);
+        check(&line, (13, 4), 5.0);
+    }
+
+    #[test]
+    fn polygons() {
+        let triangle = Triangle::new((0, 0), (20, 0), (0, 20));
+        check(&triangle, (2, 5), -2.0);
+        check(&triangle, (-3, 5), 3.0);
+        check(&triangle, (-3, -4), 5.0);
+        let u_shape = Polygon::new(&[
+            (0, 0),
+            (10, 0),
+            (10, 30),
+            (20, 30),
+            (20, 0),
+            (30, 0),
+            (30, 40),
+            (0, 40),
+        ]);
+        check(&u_shape, (15, 10), 5.0);
+        check(&u_shape, (5, 10), -5.0);
+        check(&u_shape, (15, 35), -5.0);
+        for point in [(15, 10), (5, 10), (15, 35), (40, 50)] {
+            let point = coord!(point.0, point.1);
+            assert_eq!(
+                u_shape.signed_distance(point),
+                u_shape.distance_to_point(point)
+            );
+        }
+    }
+
+    #[test]
+    fn ellipse() {
+        let ellipse = Ellipse::new((0, 0), 40, 20);
+        check(&ellipse, (20, 0), 0.0);
+        check(&ellipse, (0, 10), 0.0);
+        check(&ellipse, (25, 0), 5.0);
+        check(&ellipse, (0, -15), 5.0);
+        check(&ellipse, (15, 0), -5.0);
+        let rotated = ellipse.rotate(90);
+        check(&rotated, (0, 20), 0.0);
+        check(&rotated, (0, 25), 5.0);
+        check(&rotated, (15, 0), 5.0);
+    }
+
+    #[test]
+    fn other_shapes() {
+        let annulus = Annulus::new((0, 0), 10, 20);
+        check(&annulus, (0, 0), 10.0);
+        check(&annulus, (15, 0), -5.0);
+        check(&annulus, (25, 0), 5.0);
+        let capsule = Capsule::new((0, 0), (20, 0), 5);
+        check(&capsule, (10, 0), -5.0);
+        check(&capsule, (30, 0), 5.0);
+        let shape_box = ShapeBox::from(Circle::new((0, 0), 10));
+        check(&shape_box, (0, 15), 5.0);
+    }
+
+    #[test]
+    fn grid() {
+        let rect = Rect::new((5, 5), (15, 15));
+        let field = DistanceField::new(&rect, &Rect::new((0, 0), (20, 10)));
+        assert_eq!(field.width(), 21);
+        assert_eq!(field.height(), 11);
+        assert_eq!(field.values().len(), 21 * 11);
+        assert_eq!(field.get(coord!(0, 5)), Some(5.0));
+        assert_eq!(field.get(coord!(10, 10)), Some(-5.0));
+        assert_eq!(field.get(coord!(21, 5)), None);
+        assert_eq!(field.get(coord!(-1, 5)), None);
+        assert_eq!(field.sample(0.5, 5.0), 4.5);
+        assert_eq!(field.sample(-10.0, 5.0), 5.0);
+    }
+}
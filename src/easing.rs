@@ -0,0 +1,408 @@
+//! Easing curves and keyframe tracks for animating anything that implements [Lerp]
+//!
+//! # Usage
+//! ```rust
+//!# use graphics_shapes::prelude::*;
+//! let track = Keyframes::new()
+//!     .with_frame(0.0, coord!(0, 0), Easing::Linear)
+//!     .with_frame(1.0, coord!(100, 0), Easing::QuadInOut)
+//!     .with_frame(2.0, coord!(100, 100), Easing::Linear);
+//! assert_eq!(track.value_at(0.5), Some(coord!(50, 0)));
+//! assert_eq!(track.value_at(1.5), Some(coord!(100, 50)));
+//! assert_eq!(track.value_at(5.0), Some(coord!(100, 100)));
+//! ```
+
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// How many steps are used to solve [Easing::CubicBezier] curves
+const BEZIER_ITERATIONS: usize = 16;
+
+/// Timing curves, these change how a percent progresses between `0.0` and `1.0`
+///
+/// * `In` curves start slowly
+/// * `Out` curves end slowly
+/// * `InOut` curves start and end slowly
+///
+/// `Elastic` and `Back` curves go past `0.0` and/or `1.0` before settling
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    /// Custom curve, the same as CSS `cubic-bezier(x1, y1, x2, y2)`
+    ///
+    /// The curve goes from (0, 0) to (1, 1) with the two control points between them,
+    /// the x values should be between `0.0` and `1.0`
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Eased value for `percent`, `percent` is clamped to `0.0..=1.0`
+    ///
+    /// # Usage
+    /// ```rust
+    ///# use graphics_shapes::easing::Easing;
+    /// assert_eq!(Easing::QuadIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::QuadOut.apply(0.5), 0.75);
+    /// ```
+    #[must_use]
+    pub fn apply(&self, percent: f32) -> f32 {
+        let t = percent.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t).powi(2),
+            Easing::QuadInOut => in_out(t, |t| t * t),
+            Easing::CubicIn => t.powi(3),
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut => in_out(t, |t| t.powi(3)),
+            Easing::SineIn => 1.0 - (t * FRAC_PI_2).cos(),
+            Easing::SineOut => (t * FRAC_PI_2).sin(),
+            Easing::SineInOut => -((t * PI).cos() - 1.0) / 2.0,
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => 1.0 - elastic_in(1.0 - t),
+            Easing::ElasticInOut => in_out(t, elastic_in),
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+            Easing::BackIn => back_in(t),
+            Easing::BackOut => 1.0 - back_in(1.0 - t),
+            Easing::BackInOut => in_out(t, back_in),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(t, (*x1, *y1), (*x2, *y2)),
+        }
+    }
+
+    /// Eased point at `percent` between `start` and `end`, see [Lerp::lerp]
+    ///
+    /// # Usage
+    /// ```rust
+    ///# use graphics_shapes::prelude::*;
+    /// assert_eq!(Easing::QuadIn.lerp(coord!(0, 0), coord!(100, 0), 0.5), coord!(25, 0));
+    /// ```
+    #[must_use]
+    pub fn lerp<T: Lerp>(&self, start: T, end: T, percent: f32) -> T {
+        start.lerp(end, self.apply(percent))
+    }
+}
+
+/// First half is `ease_in`, second half is `ease_in` mirrored
+fn in_out(t: f32, ease_in: fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) / 2.0
+    } else {
+        1.0 - ease_in((1.0 - t) * 2.0) / 2.0
+    }
+}
+
+fn elastic_in(t: f32) -> f32 {
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+    -(2.0_f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * (TAU / 3.0)).sin()
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const STRENGTH: f32 = 7.5625;
+    const WIDTH: f32 = 2.75;
+    if t < 1.0 / WIDTH {
+        STRENGTH * t * t
+    } else if t < 2.0 / WIDTH {
+        let t = t - 1.5 / WIDTH;
+        STRENGTH * t * t + 0.75
+    } else if t < 2.5 / WIDTH {
+        let t = t - 2.25 / WIDTH;
+        STRENGTH * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / WIDTH;
+        STRENGTH * t * t + 0.984375
+    }
+}
+
+fn back_in(t: f32) -> f32 {
+    const OVERSHOOT: f32 = 1.70158;
+    (OVERSHOOT + 1.0) * t.powi(3) - OVERSHOOT * t * t
+}
+
+/// Value of one axis of a cubic bezier from 0 to 1 with control values `first` and `second`
+fn bezier_axis(s: f32, first: f32, second: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * first + 3.0 * inv * s * s * second + s * s * s
+}
+
+/// Find where the curve reaches `t` horizontally (by bisection) and return the height there
+fn cubic_bezier(t: f32, first: (f32, f32), second: (f32, f32)) -> f32 {
+    let (mut low, mut high) = (0.0, 1.0);
+    let mut s = t;
+    for _ in 0..BEZIER_ITERATIONS {
+        let x = bezier_axis(s, first.0, second.0);
+        if x < t {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    bezier_axis(s, first.1, second.1)
+}
+
+/// A value at a point in time, see [Keyframes]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe<T: Lerp> {
+    time: f32,
+    value: T,
+    easing: Easing,
+}
+
+impl<T: Lerp> Keyframe<T> {
+    #[inline]
+    #[must_use]
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Curve used when moving from this keyframe to the next
+    #[inline]
+    #[must_use]
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+}
+
+/// Track of values at points in time, values between keyframes are calculated with [Lerp]
+///
+/// Before the first keyframe the value is the first keyframe's value and after the last keyframe
+/// it's the last keyframe's value
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes<T: Lerp> {
+    frames: Vec<Keyframe<T>>,
+}
+
+impl<T: Lerp> Default for Keyframes<T> {
+    fn default() -> Self {
+        Self { frames: vec![] }
+    }
+}
+
+impl<T: Lerp> Keyframes<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Same as [Keyframes::insert] but returns the track
+    #[must_use]
+    pub fn with_frame(mut self, time: f32, value: T, easing: Easing) -> Self {
+        self.insert(time, value, easing);
+        self
+    }
+
+    /// Add a keyframe, `easing` is used between this keyframe and the next one
+    ///
+    /// Keyframes are kept in time order, a keyframe at the same time as an existing one replaces it
+    pub fn insert(&mut self, time: f32, value: T, easing: Easing) {
+        let frame = Keyframe {
+            time,
+            value,
+            easing,
+        };
+        let index = self.frames.partition_point(|frame| frame.time < time);
+        match self.frames.get(index) {
+            Some(existing) if existing.time == time => self.frames[index] = frame,
+            _ => self.frames.insert(index, frame),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn frames(&self) -> &[Keyframe<T>] {
+        &self.frames
+    }
+
+    /// Time of the last keyframe, or `0.0` if there are none
+    #[must_use]
+    pub fn duration(&self) -> f32 {
+        self.frames
+            .last()
+            .map(|frame| frame.time)
+            .unwrap_or_default()
+    }
+}
+
+impl<T: Lerp + Clone> Keyframes<T> {
+    /// Value at `time`, returns `None` if there are no keyframes
+    #[must_use]
+    pub fn value_at(&self, time: f32) -> Option<T> {
+        let first = self.frames.first()?;
+        let index = self.frames.partition_point(|frame| frame.time <= time);
+        if index == 0 {
+            return Some(first.value.clone());
+        }
+        let before = &self.frames[index - 1];
+        match self.frames.get(index) {
+            None => Some(before.value.clone()),
+            Some(after) => {
+                let percent = (time - before.time) / (after.time - before.time);
+                Some(
+                    before
+                        .easing
+                        .lerp(before.value.clone(), after.value.clone(), percent),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ALL: [Easing; 20] = [
+        Easing::Linear,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+        Easing::CubicBezier(0.25, 0.1, 0.25, 1.0),
+    ];
+
+    fn close(actual: f32, expected: f32) -> bool {
+        (actual - expected).abs() < 0.001
+    }
+
+    #[test]
+    fn end_points() {
+        for easing in ALL {
+            assert!(close(easing.apply(0.0), 0.0), "{easing:?}");
+            assert!(close(easing.apply(1.0), 1.0), "{easing:?}");
+            assert!(close(easing.apply(-1.0), 0.0), "{easing:?}");
+            assert!(close(easing.apply(2.0), 1.0), "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn in_out_are_symmetric() {
+        for easing in [
+            Easing::QuadInOut,
+            Easing::CubicInOut,
+            Easing::SineInOut,
+            Easing::ElasticInOut,
+            Easing::BounceInOut,
+            Easing::BackInOut,
+        ] {
+            assert!(close(easing.apply(0.5), 0.5), "{easing:?}");
+            for i in 0..=10 {
+                let t = i as f32 / 10.0;
+                assert!(
+                    close(easing.apply(t), 1.0 - easing.apply(1.0 - t)),
+                    "{easing:?} {t}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn shapes() {
+        assert!(close(Easing::CubicIn.apply(0.5), 0.125));
+        assert!(close(Easing::CubicOut.apply(0.5), 0.875));
+        assert!(close(Easing::SineIn.apply(0.5), 0.29289));
+        assert!(close(Easing::BounceOut.apply(1.0 / 2.75), 1.0));
+        assert!(Easing::BackIn.apply(0.2) < 0.0);
+        assert!(Easing::BackOut.apply(0.8) > 1.0);
+        assert!(Easing::ElasticOut.apply(0.1) > 1.0);
+        for i in 1..10 {
+            let t = i as f32 / 10.0;
+            assert!(Easing::QuadIn.apply(t) < t);
+            assert!(Easing::QuadOut.apply(t) > t);
+        }
+    }
+
+    #[test]
+    fn cubic_bezier() {
+        // control points on the diagonal are linear
+        let linear = Easing::CubicBezier(0.25, 0.25, 0.75, 0.75);
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert!(close(linear.apply(t), t));
+        }
+        // CSS ease-in-out
+        let ease = Easing::CubicBezier(0.42, 0.0, 0.58, 1.0);
+        assert!(close(ease.apply(0.5), 0.5));
+        assert!(ease.apply(0.2) < 0.2);
+        assert!(ease.apply(0.8) > 0.8);
+    }
+
+    #[test]
+    fn keyframes() {
+        let empty: Keyframes<isize> = Keyframes::new();
+        assert_eq!(empty.value_at(1.0), None);
+        assert_eq!(empty.duration(), 0.0);
+
+        let mut track = Keyframes::new();
+        track.insert(2.0, 20, Easing::Linear);
+        track.insert(0.0, 0, Easing::QuadIn);
+        track.insert(4.0, 0, Easing::Linear);
+        assert_eq!(track.duration(), 4.0);
+        assert_eq!(track.frames()[1].value(), &20);
+        assert_eq!(track.value_at(-1.0), Some(0));
+        assert_eq!(track.value_at(1.0), Some(5));
+        assert_eq!(track.value_at(2.0), Some(20));
+        assert_eq!(track.value_at(3.0), Some(10));
+        assert_eq!(track.value_at(10.0), Some(0));
+
+        track.insert(2.0, 40, Easing::Linear);
+        assert_eq!(track.frames().len(), 3);
+        assert_eq!(track.value_at(2.0), Some(40));
+    }
+
+    #[test]
+    fn animate_shape() {
+        let track = Keyframes::new()
+            .with_frame(0.0, Circle::new((0, 0), 10), Easing::SineInOut)
+            .with_frame(1.0, Circle::new((100, 50), 30), Easing::Linear);
+        assert_eq!(track.value_at(0.5), Some(Circle::new((50, 25), 20)));
+        assert_eq!(track.value_at(1.0), Some(Circle::new((100, 50), 30)));
+    }
+}
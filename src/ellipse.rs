@@ -64,6 +64,15 @@ impl Ellipse {
         self.rotation
     }
 
+    /// Horizontal and vertical radius before rotation
+    pub(crate) fn radii(&self) -> (f32, f32) {
+        let length = |diff: Coord| ((diff.x * diff.x + diff.y * diff.y) as f32).sqrt();
+        (
+            length(self.right - self.center),
+            length(self.top - self.center),
+        )
+    }
+
     #[inline(always)]
     fn no_rotate_point(x: isize, y: isize, _: Coord, _: isize) -> Coord {
         coord!(x, y)
@@ -243,6 +252,27 @@ impl Shape for Ellipse {
         output.into_iter().collect()
     }
 
+    fn convex_hull(&self) -> Polygon {
+        Polygon::convex_hull(&self.as_polygon().points())
+    }
+
+    /// Approximate, the error grows the further `point` is from the outline
+    fn signed_distance(&self, point: Coord) -> f32 {
+        let (width, height) = self.radii();
+        let rads = (self.rotation as f32).to_radians();
+        let dx = (point.x - self.center.x) as f32;
+        let dy = (point.y - self.center.y) as f32;
+        let x = dx * rads.cos() + dy * rads.sin();
+        let y = dy * rads.cos() - dx * rads.sin();
+        let k0 = ((x / width).powi(2) + (y / height).powi(2)).sqrt();
+        let k1 = ((x / (width * width)).powi(2) + (y / (height * height)).powi(2)).sqrt();
+        if k1 == 0.0 {
+            -width.min(height)
+        } else {
+            k0 * (k0 - 1.0) / k1
+        }
+    }
+
     fn to_shape_box(&self) -> ShapeBox {
         ShapeBox::Ellipse(self.clone())
     }
@@ -302,7 +332,9 @@ impl Ellipse {
         let segments = (((w + h) / 2.0) * 20.0).sqrt().floor().max(8.0) as usize;
         let points = discretise_ellipse(x, y, w, h, segments);
 
-        Polygon::from_points(&points).rotate(self.angle())
+        Polygon::from_points(&points)
+            .rotate(self.angle())
+            .to_clockwise()
     }
 }
 
@@ -1,4 +1,5 @@
-use crate::Coord;
+use crate::polygon::Polygon;
+use crate::{Coord, Shape};
 
 /// Scale `points` (move them towards or away) around the `center` by `factor`
 ///
@@ -32,10 +33,309 @@ pub fn rotate_points(center: Coord, points: &[Coord], degrees: isize) -> Vec<Coo
     output
 }
 
+/// Distance from `point` to the closest point on the line from `start` to `end`
+#[must_use]
+pub fn distance_to_segment(point: Coord, start: Coord, end: Coord) -> f32 {
+    let (px, py) = (point.x as f32, point.y as f32);
+    let (sx, sy) = (start.x as f32, start.y as f32);
+    let (dx, dy) = ((end.x - start.x) as f32, (end.y - start.y) as f32);
+    let len_sq = dx * dx + dy * dy;
+    let t = if len_sq == 0.0 {
+        0.0
+    } else {
+        (((px - sx) * dx + (py - sy) * dy) / len_sq).clamp(0.0, 1.0)
+    };
+    let (nx, ny) = (sx + dx * t, sy + dy * t);
+    ((px - nx) * (px - nx) + (py - ny) * (py - ny)).sqrt()
+}
+
+/// Distance from `point` to the closest side of the polygon made from `points`,
+/// negative if `point` is inside (using the even-odd rule)
+#[must_use]
+pub fn polygon_signed_distance(points: &[Coord], point: Coord) -> f32 {
+    if points.is_empty() {
+        return f32::MAX;
+    }
+    let (px, py) = (point.x as f32, point.y as f32);
+    let mut distance = f32::MAX;
+    let mut inside = false;
+    let mut j = points.len() - 1;
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[j]);
+        distance = distance.min(distance_to_segment(point, a, b));
+        let (ax, ay) = (a.x as f32, a.y as f32);
+        let (bx, by) = (b.x as f32, b.y as f32);
+        if (ay > py) != (by > py) && px < (bx - ax) * (py - ay) / (by - ay) + ax {
+            inside = !inside;
+        }
+        j = i;
+    }
+    if inside {
+        -distance
+    } else {
+        distance
+    }
+}
+
+/// Returns true if the lines `a1`-`a2` and `b1`-`b2` cross each other
+///
+/// Lines that only touch (at an end or by overlapping) don't count
+#[must_use]
+pub fn segments_cross(a1: Coord, a2: Coord, b1: Coord, b2: Coord) -> bool {
+    let d1 = (a2 - a1).cross_product(b1 - a1).signum();
+    let d2 = (a2 - a1).cross_product(b2 - a1).signum();
+    let d3 = (b2 - b1).cross_product(a1 - b1).signum();
+    let d4 = (b2 - b1).cross_product(a2 - b1).signum();
+    d1 * d2 < 0 && d3 * d4 < 0
+}
+
+/// Remove points using the Ramer–Douglas–Peucker algorithm
+///
+/// Every removed point will be at most `tolerance` away from the simplified lines.
+/// If `closed` the last point is treated as connected to the first (like a [Polygon](crate::polygon::Polygon))
+/// and at least 3 points are kept, otherwise the first and last points are always kept.
+///
+/// Shortcuts that would cross the original lines are not taken, so the simplified lines
+/// won't cross where the original didn't
+#[must_use]
+pub fn simplify_rdp(points: &[Coord], tolerance: f32, closed: bool) -> Vec<Coord> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut path = points.to_vec();
+    if closed {
+        path.push(points[0]);
+    }
+    let last = path.len() - 1;
+    let mut keep = vec![false; path.len()];
+    keep[0] = true;
+    keep[last] = true;
+    if closed {
+        let far = (1..last)
+            .max_by_key(|&i| path[0].distance(path[i]))
+            .unwrap_or(1);
+        keep[far] = true;
+        rdp_range(&path, 0, far, tolerance, &mut keep);
+        rdp_range(&path, far, last, tolerance, &mut keep);
+        if keep.iter().filter(|kept| **kept).count() < 4 {
+            let extra = (1..last)
+                .filter(|&i| i != far)
+                .max_by(|&a, &b| {
+                    distance_to_segment(path[a], path[0], path[far])
+                        .total_cmp(&distance_to_segment(path[b], path[0], path[far]))
+                })
+                .unwrap_or(far);
+            keep[extra] = true;
+        }
+        keep[last] = false;
+    } else {
+        rdp_range(&path, 0, last, tolerance, &mut keep);
+    }
+    path.iter()
+        .zip(keep)
+        .filter_map(|(point, kept)| kept.then_some(*point))
+        .collect()
+}
+
+fn rdp_range(path: &[Coord], start: usize, end: usize, tolerance: f32, keep: &mut [bool]) {
+    if end <= start + 1 {
+        return;
+    }
+    let (index, dist) = (start + 1..end)
+        .map(|i| (i, distance_to_segment(path[i], path[start], path[end])))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+    let crosses = path.windows(2).enumerate().any(|(i, line)| {
+        (i < start || i >= end) && segments_cross(path[start], path[end], line[0], line[1])
+    });
+    if dist > tolerance || crosses {
+        keep[index] = true;
+        rdp_range(path, start, index, tolerance, keep);
+        rdp_range(path, index, end, tolerance, keep);
+    }
+}
+
+/// Remove points using the Visvalingam–Whyatt algorithm
+///
+/// The point that makes the smallest triangle with its neighbours is removed repeatedly until
+/// every remaining triangle has an area of at least `min_area`.
+/// If `closed` the last point is treated as connected to the first (like a [Polygon](crate::polygon::Polygon))
+/// and at least 3 points are kept, otherwise the first and last points are always kept.
+///
+/// Points are not removed if the new line would cross another line
+#[must_use]
+pub fn simplify_visvalingam(points: &[Coord], min_area: f32, closed: bool) -> Vec<Coord> {
+    let min_len = if closed { 3 } else { 2 };
+    let mut points = points.to_vec();
+    while points.len() > min_len {
+        let len = points.len();
+        let neighbours = |i: usize| {
+            if closed {
+                ((i + len - 1) % len, (i + 1) % len)
+            } else {
+                (i - 1, i + 1)
+            }
+        };
+        let range = if closed { 0..len } else { 1..len - 1 };
+        let mut candidates: Vec<(usize, f32)> = range
+            .map(|i| {
+                let (prev, next) = neighbours(i);
+                let area = (points[prev] - points[i])
+                    .cross_product(points[next] - points[i])
+                    .unsigned_abs() as f32
+                    / 2.0;
+                (i, area)
+            })
+            .filter(|(_, area)| *area < min_area)
+            .collect();
+        candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let lines = if closed { len } else { len - 1 };
+        let removable = candidates.into_iter().find(|(i, _)| {
+            let (prev, next) = neighbours(*i);
+            !(0..lines).any(|l| {
+                let end = (l + 1) % len;
+                l != prev
+                    && l != *i
+                    && segments_cross(points[prev], points[next], points[l], points[end])
+            })
+        });
+        match removable {
+            Some((i, _)) => {
+                points.remove(i);
+            }
+            None => break,
+        }
+    }
+    points
+}
+
+/// Every point that is the sum of a point in `a` and a point in `b`
+///
+/// Both shapes are treated as their [convex hull](Shape::convex_hull) (so circles and ellipses are
+/// approximated), the result is always convex and clockwise
+///
+/// Useful for path finding, sliding `b` around the edge of `a` will trace the result
+/// (if `b` was centered on (0,0))
+#[must_use]
+pub fn minkowski_sum(a: &dyn Shape, b: &dyn Shape) -> Polygon {
+    let a = a.convex_hull().points();
+    let b = b.convex_hull().points();
+    let points: Vec<Coord> = a
+        .iter()
+        .flat_map(|a| b.iter().map(move |b| *a + *b))
+        .collect();
+    Polygon::convex_hull(&points)
+}
+
+/// Every point that is a point in `a` minus a point in `b`
+///
+/// Both shapes are treated as their [convex hull](Shape::convex_hull) (so circles and ellipses are
+/// approximated), the result is always convex and clockwise
+///
+/// The result contains (0,0) if the shapes overlap
+#[must_use]
+pub fn minkowski_difference(a: &dyn Shape, b: &dyn Shape) -> Polygon {
+    let a = a.convex_hull().points();
+    let b = b.convex_hull().points();
+    let points: Vec<Coord> = a
+        .iter()
+        .flat_map(|a| b.iter().map(move |b| *a - *b))
+        .collect();
+    Polygon::convex_hull(&points)
+}
+
 #[cfg(test)]
 mod test {
+    use crate::general_math::{
+        distance_to_segment, minkowski_difference, minkowski_sum, segments_cross,
+    };
+    use crate::prelude::*;
     use crate::rotate_points;
 
+    #[test]
+    fn minkowski_sum_rects() {
+        let a = Rect::new((0, 0), (10, 10));
+        let b = Rect::new((-2, -3), (2, 3));
+        let sum = minkowski_sum(&a, &b);
+        assert!(sum.is_convex());
+        assert_eq!(sum.winding(), Winding::Clockwise);
+        assert_eq!(sum, Rect::new((-2, -3), (12, 13)).as_polygon());
+    }
+
+    #[test]
+    fn minkowski_sum_triangle_circle() {
+        let triangle = Triangle::new((0, 0), (20, 0), (0, 20));
+        let circle = Circle::new((0, 0), 10);
+        let sum = minkowski_sum(&triangle, &circle);
+        assert!(sum.is_convex());
+        assert!(sum.contains(coord!(-8, 10)));
+        assert!(sum.contains(coord!(10, -8)));
+        assert!(!sum.contains(coord!(-11, 10)));
+        assert!(!sum.contains(coord!(10, -11)));
+        let polygon = Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10)]);
+        let sum = minkowski_sum(&polygon, &triangle);
+        assert_eq!(
+            sum.points(),
+            vec![
+                coord!(0, 0),
+                coord!(30, 0),
+                coord!(30, 10),
+                coord!(10, 30),
+                coord!(0, 30)
+            ]
+        );
+    }
+
+    #[test]
+    fn minkowski_difference_overlap() {
+        let a = Rect::new((0, 0), (10, 10));
+        let overlapping = Rect::new((5, 5), (15, 15));
+        let separate = Rect::new((20, 0), (30, 10));
+        let diff = minkowski_difference(&a, &overlapping);
+        assert!(diff.is_convex());
+        assert!(diff.contains(coord!(0, 0)));
+        assert_eq!(diff, Rect::new((-15, -15), (5, 5)).as_polygon());
+        assert!(!minkowski_difference(&a, &separate).contains(coord!(0, 0)));
+    }
+
+    #[test]
+    fn segment_distance() {
+        assert_eq!(
+            distance_to_segment(coord!(5, 5), coord!(0, 0), coord!(10, 0)),
+            5.0
+        );
+        assert_eq!(
+            distance_to_segment(coord!(13, 4), coord!(0, 0), coord!(10, 0)),
+            5.0
+        );
+        assert_eq!(
+            distance_to_segment(coord!(3, 4), coord!(0, 0), coord!(0, 0)),
+            5.0
+        );
+    }
+
+    #[test]
+    fn crossing() {
+        assert!(segments_cross(
+            coord!(0, 0),
+            coord!(10, 10),
+            coord!(0, 10),
+            coord!(10, 0)
+        ));
+        assert!(!segments_cross(
+            coord!(0, 0),
+            coord!(10, 10),
+            coord!(10, 10),
+            coord!(20, 0)
+        ));
+        assert!(!segments_cross(
+            coord!(0, 0),
+            coord!(10, 0),
+            coord!(0, 5),
+            coord!(10, 5)
+        ));
+    }
+
     #[test]
     fn one_point_rotation() {
         let center = coord!(20, 20);
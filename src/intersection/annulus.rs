@@ -0,0 +1,137 @@
+use crate::intersection::shared::{
+    annulus_annulus, annulus_circle, annulus_shape, polygon_with_holes_shape,
+};
+use crate::prelude::*;
+
+impl IntersectsShape for Annulus {
+    fn intersects_rect(&self, rect: &Rect) -> bool {
+        annulus_shape(self, rect)
+    }
+
+    fn intersects_circle(&self, circle: &Circle) -> bool {
+        annulus_circle(self, circle)
+    }
+
+    fn intersects_line(&self, line: &Line) -> bool {
+        annulus_shape(self, line)
+    }
+
+    fn intersects_triangle(&self, triangle: &Triangle) -> bool {
+        annulus_shape(self, triangle)
+    }
+
+    fn intersects_ellipse(&self, ellipse: &Ellipse) -> bool {
+        annulus_shape(self, &ellipse.as_polygon())
+    }
+
+    fn intersects_polygon(&self, polygon: &Polygon) -> bool {
+        annulus_shape(self, polygon)
+    }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        annulus_annulus(self, annulus)
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        annulus_shape(self, capsule)
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        polygon_with_holes_shape(polygon, self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn line_in_hole() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let line = Line::new((45, 45), (55, 55));
+        assert!(!annulus.intersects_line(&line));
+        assert!(!line.intersects_annulus(&annulus));
+    }
+
+    #[test]
+    fn line_crossing_inner_edge() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let line = Line::new((50, 50), (50, 25));
+        assert!(annulus.intersects_line(&line));
+        assert!(line.intersects_annulus(&annulus));
+    }
+
+    #[test]
+    fn line_crossing_outer_edge() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let line = Line::new((50, 25), (50, 0));
+        assert!(annulus.intersects_line(&line));
+        assert!(line.intersects_annulus(&annulus));
+    }
+
+    #[test]
+    fn rect() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let in_hole = Rect::new((45, 45), (55, 55));
+        let over_band = Rect::new((60, 45), (90, 55));
+        let outside = Rect::new((100, 100), (120, 120));
+        assert!(!annulus.intersects_rect(&in_hole));
+        assert!(!in_hole.intersects_annulus(&annulus));
+        assert!(annulus.intersects_rect(&over_band));
+        assert!(over_band.intersects_annulus(&annulus));
+        assert!(!annulus.intersects_rect(&outside));
+        assert!(!outside.intersects_annulus(&annulus));
+    }
+
+    #[test]
+    fn triangle() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let in_hole = Triangle::new((45, 45), (55, 45), (50, 55));
+        let over_band = Triangle::new((50, 10), (60, 40), (40, 40));
+        assert!(!annulus.intersects_triangle(&in_hole));
+        assert!(!in_hole.intersects_annulus(&annulus));
+        assert!(annulus.intersects_triangle(&over_band));
+        assert!(over_band.intersects_annulus(&annulus));
+    }
+
+    #[test]
+    fn polygon() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let in_hole = Polygon::new(&[(45, 45), (55, 45), (55, 55), (45, 55)]);
+        let over_band = Polygon::new(&[(75, 45), (90, 45), (90, 55), (75, 55)]);
+        assert!(!annulus.intersects_polygon(&in_hole));
+        assert!(!in_hole.intersects_annulus(&annulus));
+        assert!(annulus.intersects_polygon(&over_band));
+        assert!(over_band.intersects_annulus(&annulus));
+    }
+
+    #[test]
+    fn ellipse() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let in_hole = Ellipse::new((50, 50), 10, 6);
+        let over_band = Ellipse::new((80, 50), 20, 10);
+        assert!(!annulus.intersects_ellipse(&in_hole));
+        assert!(!in_hole.intersects_annulus(&annulus));
+        assert!(annulus.intersects_ellipse(&over_band));
+        assert!(over_band.intersects_annulus(&annulus));
+    }
+
+    #[test]
+    fn circle() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let over_band = Circle::new((80, 50), 5);
+        assert!(annulus.intersects_circle(&over_band));
+        assert!(over_band.intersects_annulus(&annulus));
+    }
+
+    #[test]
+    fn annulus() {
+        let annulus = Annulus::new((50, 50), 20, 30);
+        let overlapping = Annulus::new((90, 50), 5, 15);
+        let separate = Annulus::new((150, 50), 5, 15);
+        assert!(annulus.intersects_annulus(&overlapping));
+        assert!(overlapping.intersects_annulus(&annulus));
+        assert!(!annulus.intersects_annulus(&separate));
+        assert!(!separate.intersects_annulus(&annulus));
+    }
+}
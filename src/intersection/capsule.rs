@@ -0,0 +1,150 @@
+use crate::intersection::shared::{
+    annulus_shape, capsule_circle, capsule_lines, polygon_with_holes_shape,
+};
+use crate::prelude::*;
+
+impl IntersectsShape for Capsule {
+    fn intersects_rect(&self, rect: &Rect) -> bool {
+        capsule_lines(self, &rect.as_lines())
+    }
+
+    fn intersects_circle(&self, circle: &Circle) -> bool {
+        capsule_circle(self, circle)
+    }
+
+    fn intersects_line(&self, line: &Line) -> bool {
+        capsule_lines(self, std::slice::from_ref(line))
+    }
+
+    fn intersects_triangle(&self, triangle: &Triangle) -> bool {
+        capsule_lines(self, &triangle.as_lines())
+    }
+
+    fn intersects_ellipse(&self, ellipse: &Ellipse) -> bool {
+        capsule_lines(self, &ellipse.as_polygon().as_lines())
+    }
+
+    fn intersects_polygon(&self, polygon: &Polygon) -> bool {
+        capsule_lines(self, &polygon.as_lines())
+    }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        annulus_shape(annulus, self)
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        capsule_lines(self, &capsule.as_polygon().as_lines())
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        polygon_with_holes_shape(polygon, self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn line() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let crossing = Line::new((40, 0), (40, 40));
+        let inside = Line::new((30, 20), (50, 20));
+        let touching_cap = Line::new((70, 0), (70, 40));
+        let outside = Line::new((72, 0), (72, 40));
+        assert!(capsule.intersects_line(&crossing));
+        assert!(crossing.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_line(&inside));
+        assert!(!inside.intersects_capsule(&capsule));
+        assert!(capsule.intersects_line(&touching_cap));
+        assert!(touching_cap.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_line(&outside));
+        assert!(!outside.intersects_capsule(&capsule));
+    }
+
+    #[test]
+    fn rect() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let crossing = Rect::new((50, 0), (100, 40));
+        let surrounding = Rect::new((0, 0), (100, 40));
+        let inside = Rect::new((30, 15), (50, 25));
+        assert!(capsule.intersects_rect(&crossing));
+        assert!(crossing.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_rect(&surrounding));
+        assert!(!surrounding.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_rect(&inside));
+        assert!(!inside.intersects_capsule(&capsule));
+    }
+
+    #[test]
+    fn circle() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let crossing = Circle::new((40, 35), 10);
+        let inside = Circle::new((40, 20), 5);
+        let surrounding = Circle::new((40, 20), 40);
+        let outside = Circle::new((40, 50), 10);
+        assert!(capsule.intersects_circle(&crossing));
+        assert!(crossing.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_circle(&inside));
+        assert!(!inside.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_circle(&surrounding));
+        assert!(!surrounding.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_circle(&outside));
+        assert!(!outside.intersects_capsule(&capsule));
+    }
+
+    #[test]
+    fn triangle() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let crossing = Triangle::new((40, 25), (30, 50), (50, 50));
+        let outside = Triangle::new((40, 35), (30, 50), (50, 50));
+        assert!(capsule.intersects_triangle(&crossing));
+        assert!(crossing.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_triangle(&outside));
+        assert!(!outside.intersects_capsule(&capsule));
+    }
+
+    #[test]
+    fn polygon() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let crossing = Polygon::new(&[(0, 0), (30, 0), (30, 15), (0, 15)]);
+        let outside = Polygon::new(&[(0, 40), (30, 40), (30, 55), (0, 55)]);
+        assert!(capsule.intersects_polygon(&crossing));
+        assert!(crossing.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_polygon(&outside));
+        assert!(!outside.intersects_capsule(&capsule));
+    }
+
+    #[test]
+    fn ellipse() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let crossing = Ellipse::new((40, 30), 30, 10);
+        let outside = Ellipse::new((40, 60), 30, 10);
+        assert!(capsule.intersects_ellipse(&crossing));
+        assert!(crossing.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_ellipse(&outside));
+        assert!(!outside.intersects_capsule(&capsule));
+    }
+
+    #[test]
+    fn annulus() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let crossing = Annulus::new((80, 20), 5, 15);
+        let in_hole = Annulus::new((40, 20), 40, 50);
+        assert!(capsule.intersects_annulus(&crossing));
+        assert!(crossing.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_annulus(&in_hole));
+        assert!(!in_hole.intersects_capsule(&capsule));
+    }
+
+    #[test]
+    fn capsule() {
+        let capsule = Capsule::new((20, 20), (60, 20), 10);
+        let crossing = Capsule::new((40, 0), (40, 40), 5);
+        let outside = Capsule::new((20, 50), (60, 50), 10);
+        assert!(capsule.intersects_capsule(&crossing));
+        assert!(crossing.intersects_capsule(&capsule));
+        assert!(!capsule.intersects_capsule(&outside));
+        assert!(!outside.intersects_capsule(&capsule));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::intersection::shared::{
-    ellipse_circle, line_circle, polygon_circle, rect_circle, triangle_circle,
+    annulus_circle, capsule_circle, ellipse_circle, line_circle, polygon_circle,
+    polygon_with_holes_shape, rect_circle, triangle_circle,
 };
 use crate::prelude::*;
 
@@ -29,6 +30,18 @@ impl IntersectsShape for Circle {
     fn intersects_polygon(&self, polygon: &Polygon) -> bool {
         polygon_circle(polygon, self)
     }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        annulus_circle(annulus, self)
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        capsule_circle(capsule, self)
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        polygon_with_holes_shape(polygon, self)
+    }
 }
 
 #[cfg(test)]
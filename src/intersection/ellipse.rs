@@ -1,5 +1,6 @@
 use crate::intersection::shared::{
-    ellipse_circle, line_ellipse, lines_lines, polygon_ellipse, rect_ellipse, triangle_ellipse,
+    annulus_shape, capsule_lines, ellipse_circle, line_ellipse, lines_lines, polygon_ellipse,
+    polygon_with_holes_shape, rect_ellipse, triangle_ellipse,
 };
 use crate::prelude::*;
 
@@ -30,6 +31,18 @@ impl IntersectsShape for Ellipse {
     fn intersects_polygon(&self, polygon: &Polygon) -> bool {
         polygon_ellipse(polygon, self)
     }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        annulus_shape(annulus, &self.as_polygon())
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        capsule_lines(capsule, &self.as_polygon().as_lines())
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        polygon_with_holes_shape(polygon, self)
+    }
 }
 
 #[cfg(test)]
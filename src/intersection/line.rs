@@ -27,6 +27,18 @@ impl IntersectsShape for Line {
     fn intersects_polygon(&self, polygon: &Polygon) -> bool {
         line_polygon(self, polygon)
     }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        annulus_shape(annulus, self)
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        capsule_lines(capsule, std::slice::from_ref(self))
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        polygon_with_holes_shape(polygon, self)
+    }
 }
 
 fn direction(p: Coord, q: Coord, r: Coord) -> isize {
@@ -1,7 +1,10 @@
+pub mod annulus;
+pub mod capsule;
 pub mod circle;
 pub mod ellipse;
 pub mod line;
 pub mod polygon;
+pub mod polygon_with_holes;
 pub mod rect;
 mod shared;
 pub mod triangle;
@@ -32,4 +35,16 @@ pub trait IntersectsShape {
     /// Returns true if `polygon` intersects `self`
     #[must_use]
     fn intersects_polygon(&self, polygon: &Polygon) -> bool;
+
+    /// Returns true if `annulus` intersects `self`
+    #[must_use]
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool;
+
+    /// Returns true if `capsule` intersects `self`
+    #[must_use]
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool;
+
+    /// Returns true if `polygon` intersects `self`
+    #[must_use]
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool;
 }
@@ -0,0 +1,100 @@
+use crate::intersection::shared::polygon_with_holes_shape;
+use crate::prelude::*;
+
+impl IntersectsShape for PolygonWithHoles {
+    fn intersects_rect(&self, rect: &Rect) -> bool {
+        polygon_with_holes_shape(self, rect)
+    }
+
+    fn intersects_circle(&self, circle: &Circle) -> bool {
+        polygon_with_holes_shape(self, circle)
+    }
+
+    fn intersects_line(&self, line: &Line) -> bool {
+        polygon_with_holes_shape(self, line)
+    }
+
+    fn intersects_triangle(&self, triangle: &Triangle) -> bool {
+        polygon_with_holes_shape(self, triangle)
+    }
+
+    fn intersects_ellipse(&self, ellipse: &Ellipse) -> bool {
+        polygon_with_holes_shape(self, ellipse)
+    }
+
+    fn intersects_polygon(&self, polygon: &Polygon) -> bool {
+        polygon_with_holes_shape(self, polygon)
+    }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        polygon_with_holes_shape(self, annulus)
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        polygon_with_holes_shape(self, capsule)
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        polygon_with_holes_shape(polygon, self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn frame() -> PolygonWithHoles {
+        PolygonWithHoles::from((Rect::new((0, 0), (40, 40)), Rect::new((10, 10), (30, 30))))
+    }
+
+    #[test]
+    fn shape_in_hole() {
+        let frame = frame();
+        let rect = Rect::new((15, 15), (25, 25));
+        let circle = Circle::new((20, 20), 5);
+        let line = Line::new((12, 12), (28, 28));
+        assert!(!frame.intersects_rect(&rect));
+        assert!(!rect.intersects_polygon_with_holes(&frame));
+        assert!(!frame.intersects_circle(&circle));
+        assert!(!circle.intersects_polygon_with_holes(&frame));
+        assert!(!frame.intersects_line(&line));
+        assert!(!line.intersects_polygon_with_holes(&frame));
+    }
+
+    #[test]
+    fn shape_crossing_hole_edge() {
+        let frame = frame();
+        let rect = Rect::new((5, 15), (15, 25));
+        let line = Line::new((5, 20), (20, 20));
+        let triangle = Triangle::new((20, 5), (15, 15), (25, 15));
+        assert!(frame.intersects_rect(&rect));
+        assert!(rect.intersects_polygon_with_holes(&frame));
+        assert!(frame.intersects_line(&line));
+        assert!(line.intersects_polygon_with_holes(&frame));
+        assert!(frame.intersects_triangle(&triangle));
+        assert!(triangle.intersects_polygon_with_holes(&frame));
+    }
+
+    #[test]
+    fn shape_crossing_outer_edge() {
+        let frame = frame();
+        let circle = Circle::new((40, 20), 5);
+        let capsule = Capsule::new((35, 20), (50, 20), 2);
+        assert!(frame.intersects_circle(&circle));
+        assert!(circle.intersects_polygon_with_holes(&frame));
+        assert!(frame.intersects_capsule(&capsule));
+        assert!(capsule.intersects_polygon_with_holes(&frame));
+    }
+
+    #[test]
+    fn two_frames() {
+        let frame = frame();
+        let other = frame.translate_by(coord!(25, 0));
+        let inside =
+            PolygonWithHoles::from((Rect::new((12, 12), (28, 28)), Rect::new((15, 15), (25, 25))));
+        assert!(frame.intersects_polygon_with_holes(&other));
+        assert!(other.intersects_polygon_with_holes(&frame));
+        assert!(!frame.intersects_polygon_with_holes(&inside));
+        assert!(!inside.intersects_polygon_with_holes(&frame));
+    }
+}
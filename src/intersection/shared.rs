@@ -158,6 +158,88 @@ pub fn triangle_circle(triangle: &Triangle, circle: &Circle) -> bool {
     false
 }
 
+/// Returns true if the outlines of the circles cross or touch, unlike
+/// [Circle::intersects_circle] this is false for a circle entirely inside the other
+pub fn circle_outlines_cross(first: &Circle, second: &Circle) -> bool {
+    let dist = first.center().distance(second.center());
+    let min = first.radius().abs_diff(second.radius());
+    let max = first.radius() + second.radius();
+    (min..=max).contains(&dist)
+}
+
+/// Returns true if `circle` crosses either edge of `annulus`
+pub fn annulus_circle(annulus: &Annulus, circle: &Circle) -> bool {
+    circle_outlines_cross(&annulus.as_outer_circle(), circle)
+        || (annulus.inner_radius() > 0 && circle_outlines_cross(&annulus.as_inner_circle(), circle))
+}
+
+/// Returns true if any edge of `first` crosses any edge of `second`
+pub fn annulus_annulus(first: &Annulus, second: &Annulus) -> bool {
+    annulus_circle(first, &second.as_outer_circle())
+        || (second.inner_radius() > 0 && annulus_circle(first, &second.as_inner_circle()))
+}
+
+pub fn annulus_shape<S: IntersectsShape>(annulus: &Annulus, shape: &S) -> bool {
+    if shape.intersects_circle(&annulus.as_outer_circle()) {
+        return true;
+    }
+    annulus.inner_radius() > 0 && shape.intersects_circle(&annulus.as_inner_circle())
+}
+
+/// Returns true if the outline made of `lines` crosses the edge of `capsule`
+///
+/// As a capsule is convex the farthest point of a line from it is always one of the ends
+pub fn capsule_lines(capsule: &Capsule, lines: &[Line]) -> bool {
+    let spine = capsule.as_line();
+    let mut inside = false;
+    let mut outside = false;
+    for line in lines {
+        let nearest = if line.intersects_line(&spine) {
+            0
+        } else {
+            [
+                capsule.distance_from_spine(line.start()),
+                capsule.distance_from_spine(line.end()),
+                line.nearest_point(spine.start()).distance(spine.start()),
+                line.nearest_point(spine.end()).distance(spine.end()),
+            ]
+            .into_iter()
+            .min()
+            .unwrap_or_default()
+        };
+        inside |= nearest <= capsule.radius();
+        outside |= capsule.distance_from_spine(line.start()) > capsule.radius()
+            || capsule.distance_from_spine(line.end()) > capsule.radius();
+        if inside && outside {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn capsule_circle(capsule: &Capsule, circle: &Circle) -> bool {
+    let center_dist = capsule.distance_from_spine(circle.center());
+    let farthest_spine = circle
+        .center()
+        .distance(capsule.start())
+        .max(circle.center().distance(capsule.end()));
+    let nearest_edge = if farthest_spine < circle.radius() {
+        circle.radius() - farthest_spine
+    } else {
+        center_dist.saturating_sub(circle.radius())
+    };
+    let farthest_edge = center_dist + circle.radius();
+    nearest_edge <= capsule.radius() && farthest_edge > capsule.radius()
+}
+
+pub fn polygon_with_holes_shape<S: IntersectsShape>(polygon: &PolygonWithHoles, shape: &S) -> bool {
+    shape.intersects_polygon(polygon.outer())
+        || polygon
+            .holes()
+            .iter()
+            .any(|hole| shape.intersects_polygon(hole))
+}
+
 pub fn iterate(
     x: f64,
     y: f64,
@@ -25,4 +25,16 @@ impl IntersectsShape for Triangle {
     fn intersects_polygon(&self, polygon: &Polygon) -> bool {
         lines_lines(&polygon.as_lines(), &self.as_lines())
     }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        annulus_shape(annulus, self)
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        capsule_lines(capsule, &self.as_lines())
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        polygon_with_holes_shape(polygon, self)
+    }
 }
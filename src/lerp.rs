@@ -1,4 +1,5 @@
-use crate::Coord;
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
 
 pub trait Lerp {
     /// calculate the point at `percent` between `self` and `end`
@@ -110,15 +111,263 @@ impl Lerp for Coord {
         }
     }
 
+    /// Percent of the closest point to `point` on the line through `self` and `end`
+    ///
+    /// Points that aren't on the line are projected onto it, the result is not clamped so
+    /// points before `self` are negative and points after `end` are more than `1.0`.
+    /// If `self` and `end` are the same then `0.0` is returned
+    ///
+    /// See [Line::parameter_of] for a clamped version
     #[inline]
     fn inv_lerp(self, end: Coord, point: Coord) -> f32 {
-        (self.x.inv_lerp(end.x, point.x) + self.y.inv_lerp(end.y, point.y)) / 2.0
+        let diff = end - self;
+        let len_sq = (diff.x * diff.x + diff.y * diff.y) as f32;
+        if len_sq == 0.0 {
+            return 0.0;
+        }
+        let offset = point - self;
+        (offset.x * diff.x + offset.y * diff.y) as f32 / len_sq
+    }
+}
+
+/// Percent for `point` between `start` and `end`, or `None` if `start` and `end` are the same
+/// as any percent would match
+fn changed_percent<T: Lerp + PartialEq + Copy>(start: T, end: T, point: T) -> Option<f32> {
+    (start != end).then(|| start.inv_lerp(end, point))
+}
+
+/// Average of the percents, skipping values that didn't change
+fn average_percent(percents: &[Option<f32>]) -> f32 {
+    let changed: Vec<f32> = percents.iter().flatten().copied().collect();
+    if changed.is_empty() {
+        return 0.0;
+    }
+    changed.iter().sum::<f32>() / changed.len() as f32
+}
+
+/// See [Lerp::inv_lerp], the percents for each point are averaged
+fn inv_lerp_points(start: &[Coord], end: &[Coord], point: &[Coord]) -> f32 {
+    let percents: Vec<Option<f32>> = start
+        .iter()
+        .zip(end)
+        .zip(point)
+        .map(|((start, end), point)| changed_percent(*start, *end, *point))
+        .collect();
+    average_percent(&percents)
+}
+
+fn lerp_points(start: &[Coord], end: &[Coord], percent: f32) -> Vec<Coord> {
+    start
+        .iter()
+        .zip(end)
+        .map(|(start, end)| start.lerp(*end, percent))
+        .collect()
+}
+
+macro_rules! impl_lerp_shape {
+    ($shape_type: ty) => {
+        impl Lerp for $shape_type {
+            /// Each point moves towards the matching point in `end`
+            fn lerp(self, end: $shape_type, percent: f32) -> $shape_type {
+                <$shape_type>::from_points(&lerp_points(&self.points(), &end.points(), percent))
+            }
+
+            fn inv_lerp(self, end: $shape_type, point: $shape_type) -> f32 {
+                inv_lerp_points(&self.points(), &end.points(), &point.points())
+            }
+        }
+    };
+}
+
+impl_lerp_shape!(Line);
+impl_lerp_shape!(Rect);
+impl_lerp_shape!(Triangle);
+
+impl Lerp for Circle {
+    fn lerp(self, end: Circle, percent: f32) -> Circle {
+        Circle::new(
+            self.center().lerp(end.center(), percent),
+            self.radius().lerp(end.radius(), percent),
+        )
+    }
+
+    fn inv_lerp(self, end: Circle, point: Circle) -> f32 {
+        average_percent(&[
+            changed_percent(self.center(), end.center(), point.center()),
+            changed_percent(self.radius(), end.radius(), point.radius()),
+        ])
+    }
+}
+
+impl Lerp for Ellipse {
+    /// The center, both radii and the rotation are interpolated separately
+    fn lerp(self, end: Ellipse, percent: f32) -> Ellipse {
+        let (start_top, start_right) = radii(&self);
+        let (end_top, end_right) = radii(&end);
+        let center = self.center().lerp(end.center(), percent);
+        let rotation = self.angle().lerp(end.angle(), percent);
+        Ellipse::from_points(&[
+            center,
+            Coord::from_angle(center, start_top.lerp(end_top, percent), rotation),
+            Coord::from_angle(center, start_right.lerp(end_right, percent), rotation + 90),
+        ])
+    }
+
+    fn inv_lerp(self, end: Ellipse, point: Ellipse) -> f32 {
+        let (start_top, start_right) = radii(&self);
+        let (end_top, end_right) = radii(&end);
+        let (top, right) = radii(&point);
+        average_percent(&[
+            changed_percent(self.center(), end.center(), point.center()),
+            changed_percent(start_top, end_top, top),
+            changed_percent(start_right, end_right, right),
+            changed_percent(self.angle(), end.angle(), point.angle()),
+        ])
+    }
+}
+
+/// Vertical and horizontal radius, in that order to match [Ellipse::points]
+fn radii(ellipse: &Ellipse) -> (usize, usize) {
+    let (horizontal, vertical) = ellipse.radii();
+    (vertical.round() as usize, horizontal.round() as usize)
+}
+
+impl Lerp for Polygon {
+    /// If the polygons have a different number of points then extra points are added to the longest
+    /// sides of the polygon with fewer points, so every point has a partner
+    ///
+    /// Points are matched in order starting from the first point, if the polygons are wound in
+    /// different directions then `end` is reversed to match `self`
+    fn lerp(self, end: Polygon, percent: f32) -> Polygon {
+        let (start, end) = matched_points(&self, &end);
+        Polygon::new(&lerp_points(&start, &end, percent))
+    }
+
+    fn inv_lerp(self, end: Polygon, point: Polygon) -> f32 {
+        let (start, end) = matched_points(&self, &end);
+        let point = resample(&point.points(), start.len());
+        inv_lerp_points(&start, &end, &point)
+    }
+}
+
+/// Points for both polygons, with the same count and winding
+fn matched_points(start: &Polygon, end: &Polygon) -> (Vec<Coord>, Vec<Coord>) {
+    let end = match start.winding() {
+        Winding::Clockwise => end.to_clockwise(),
+        Winding::CounterClockwise => end.to_counter_clockwise(),
+    };
+    let (start, end) = (start.points(), end.points());
+    let count = start.len().max(end.len());
+    (resample(&start, count), resample(&end, count))
+}
+
+/// Add points to the longest sides of a closed outline until it has `count` points
+fn resample(points: &[Coord], count: usize) -> Vec<Coord> {
+    if points.is_empty() || points.len() >= count {
+        return points.to_vec();
+    }
+    let lengths: Vec<f32> = (0..points.len())
+        .map(|i| points[i].distance(points[(i + 1) % points.len()]) as f32)
+        .collect();
+    let mut splits = vec![0_usize; points.len()];
+    for _ in points.len()..count {
+        let (longest, _) = lengths
+            .iter()
+            .zip(&splits)
+            .map(|(length, splits)| length / (*splits + 1) as f32)
+            .enumerate()
+            .fold((0, f32::MIN), |best, (i, length)| {
+                if length > best.1 {
+                    (i, length)
+                } else {
+                    best
+                }
+            });
+        splits[longest] += 1;
+    }
+    let mut output = Vec::with_capacity(count);
+    for (i, point) in points.iter().enumerate() {
+        let next = points[(i + 1) % points.len()];
+        output.push(*point);
+        for split in 1..=splits[i] {
+            output.push(point.lerp(next, split as f32 / (splits[i] + 1) as f32));
+        }
+    }
+    output
+}
+
+/// Shape used when morphing between different types of shapes
+fn as_polygon(shape: &ShapeBox) -> Polygon {
+    match shape {
+        ShapeBox::Line(line) => Polygon::new(&line.points()),
+        ShapeBox::Rect(rect) => rect.as_polygon(),
+        ShapeBox::Triangle(triangle) => Polygon::new(&triangle.points()),
+        ShapeBox::Circle(circle) => circle.as_polygon(),
+        ShapeBox::Ellipse(ellipse) => ellipse.as_polygon(),
+        ShapeBox::Polygon(polygon) => polygon.clone(),
+        ShapeBox::Annulus(annulus) => annulus.as_outer_circle().as_polygon(),
+        ShapeBox::Capsule(capsule) => capsule.as_polygon(),
+        ShapeBox::PolygonWithHoles(polygon) => polygon.outer().clone(),
+        ShapeBox::Group(group) => group.convex_hull(),
+    }
+}
+
+impl Lerp for ShapeBox {
+    /// Shapes of the same type are interpolated as that type (lines, rects, triangles, circles,
+    /// ellipses and polygons), otherwise both shapes are converted to polygons and the result
+    /// is a [ShapeBox::Polygon]
+    ///
+    /// Annuluses, capsules and polygons with holes use their outer outline and groups use their
+    /// convex hull
+    fn lerp(self, end: ShapeBox, percent: f32) -> ShapeBox {
+        match (self, end) {
+            (ShapeBox::Line(start), ShapeBox::Line(end)) => {
+                ShapeBox::Line(start.lerp(end, percent))
+            }
+            (ShapeBox::Rect(start), ShapeBox::Rect(end)) => {
+                ShapeBox::Rect(start.lerp(end, percent))
+            }
+            (ShapeBox::Triangle(start), ShapeBox::Triangle(end)) => {
+                ShapeBox::Triangle(start.lerp(end, percent))
+            }
+            (ShapeBox::Circle(start), ShapeBox::Circle(end)) => {
+                ShapeBox::Circle(start.lerp(end, percent))
+            }
+            (ShapeBox::Ellipse(start), ShapeBox::Ellipse(end)) => {
+                ShapeBox::Ellipse(start.lerp(end, percent))
+            }
+            (start, end) => ShapeBox::Polygon(as_polygon(&start).lerp(as_polygon(&end), percent)),
+        }
+    }
+
+    fn inv_lerp(self, end: ShapeBox, point: ShapeBox) -> f32 {
+        match (self, end, point) {
+            (ShapeBox::Line(start), ShapeBox::Line(end), ShapeBox::Line(point)) => {
+                start.inv_lerp(end, point)
+            }
+            (ShapeBox::Rect(start), ShapeBox::Rect(end), ShapeBox::Rect(point)) => {
+                start.inv_lerp(end, point)
+            }
+            (ShapeBox::Triangle(start), ShapeBox::Triangle(end), ShapeBox::Triangle(point)) => {
+                start.inv_lerp(end, point)
+            }
+            (ShapeBox::Circle(start), ShapeBox::Circle(end), ShapeBox::Circle(point)) => {
+                start.inv_lerp(end, point)
+            }
+            (ShapeBox::Ellipse(start), ShapeBox::Ellipse(end), ShapeBox::Ellipse(point)) => {
+                start.inv_lerp(end, point)
+            }
+            (start, end, point) => {
+                as_polygon(&start).inv_lerp(as_polygon(&end), as_polygon(&point))
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::shape_box::ShapeBox;
 
     #[test]
     fn isize_simple() {
@@ -242,4 +491,144 @@ mod test {
         assert_eq!(start1.inv_lerp(end1, Coord { x: 20, y: 20 }), 2.);
         assert_eq!(start1.inv_lerp(end1, Coord { x: -10, y: -10 }), -1.);
     }
+
+    #[test]
+    fn point_degenerate_axes() {
+        let vertical_start = Coord { x: 5, y: 0 };
+        let vertical_end = Coord { x: 5, y: 10 };
+        assert_eq!(
+            vertical_start.inv_lerp(vertical_end, Coord { x: 5, y: 5 }),
+            0.5
+        );
+        assert_eq!(
+            vertical_start.inv_lerp(vertical_end, Coord { x: 5, y: 2 }),
+            0.2
+        );
+        assert_eq!(
+            vertical_end.inv_lerp(vertical_start, Coord { x: 5, y: 2 }),
+            0.8
+        );
+
+        let horizontal_start = Coord { x: 0, y: -3 };
+        let horizontal_end = Coord { x: 20, y: -3 };
+        assert_eq!(
+            horizontal_start.inv_lerp(horizontal_end, Coord { x: 5, y: -3 }),
+            0.25
+        );
+        assert_eq!(
+            horizontal_start.inv_lerp(horizontal_end, Coord { x: 30, y: -3 }),
+            1.5
+        );
+
+        // same start and end
+        let point = Coord { x: 4, y: 4 };
+        assert_eq!(point.inv_lerp(point, Coord { x: 10, y: 0 }), 0.);
+        assert_eq!(point.inv_lerp(point, point), 0.);
+    }
+
+    #[test]
+    fn point_off_line() {
+        let start = Coord { x: 0, y: 0 };
+        let end = Coord { x: 10, y: 0 };
+        assert_eq!(start.inv_lerp(end, Coord { x: 5, y: 7 }), 0.5);
+        assert_eq!(start.inv_lerp(end, Coord { x: 5, y: -7 }), 0.5);
+        assert_eq!(start.inv_lerp(end, Coord { x: -5, y: 3 }), -0.5);
+
+        let start = Coord { x: 0, y: 0 };
+        let end = Coord { x: 10, y: 10 };
+        assert_eq!(start.inv_lerp(end, Coord { x: 0, y: 10 }), 0.5);
+        assert_eq!(start.inv_lerp(end, Coord { x: 4, y: 6 }), 0.5);
+        for percent in [0.0, 0.3, 0.5, 1.0, 1.5] {
+            let point = start.lerp(end, percent);
+            assert_eq!(start.inv_lerp(end, point), percent);
+        }
+    }
+
+    #[test]
+    fn simple_shapes() {
+        let line = Line::new((0, 0), (10, 0)).lerp(Line::new((10, 10), (30, 20)), 0.5);
+        assert_eq!(line, Line::new((5, 5), (20, 10)));
+        let rect = Rect::new((0, 0), (10, 10)).lerp(Rect::new((20, 20), (40, 60)), 0.5);
+        assert_eq!(rect, Rect::new((10, 10), (25, 35)));
+        let triangle = Triangle::new((0, 0), (10, 0), (0, 10));
+        let moved = triangle.translate_by(coord!(10, 20));
+        assert_eq!(triangle.clone().lerp(moved.clone(), 0.0), triangle);
+        assert_eq!(triangle.clone().lerp(moved.clone(), 1.0), moved);
+        assert_eq!(
+            triangle.clone().lerp(moved.clone(), 0.5),
+            Triangle::new((5, 10), (15, 10), (5, 20))
+        );
+        assert_eq!(
+            triangle
+                .clone()
+                .inv_lerp(moved, Triangle::new((5, 10), (15, 10), (5, 20))),
+            0.5
+        );
+    }
+
+    #[test]
+    fn circle_and_ellipse() {
+        let circle = Circle::new((0, 0), 10).lerp(Circle::new((20, 40), 30), 0.25);
+        assert_eq!(circle, Circle::new((5, 10), 15));
+        assert_eq!(
+            Circle::new((0, 0), 10).inv_lerp(Circle::new((20, 40), 30), circle),
+            0.25
+        );
+
+        let start = Ellipse::new((0, 0), 40, 20);
+        let end = Ellipse::new((100, 0), 80, 60);
+        let middle = start.clone().lerp(end.clone(), 0.5);
+        assert_eq!(middle.center(), coord!(50, 0));
+        assert_eq!(middle.width(), 60);
+        assert_eq!(middle.height(), 40);
+        assert_eq!(start.clone().lerp(end.clone(), 0.0), start);
+        assert_eq!(start.clone().lerp(end.clone(), 1.0), end);
+        assert_eq!(start.inv_lerp(end, middle), 0.5);
+
+        let rotated = Ellipse::new((0, 0), 40, 20).rotate(90);
+        let middle = Ellipse::new((0, 0), 40, 20).lerp(rotated, 0.5);
+        assert_eq!(middle.angle(), 45);
+    }
+
+    #[test]
+    fn polygon_resampling() {
+        let square = Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10)]);
+        let pentagon = Polygon::new(&[(0, 0), (20, 0), (20, 20), (10, 30), (0, 20)]);
+        assert_eq!(square.clone().lerp(pentagon.clone(), 1.0), pentagon);
+        let start = square.clone().lerp(pentagon.clone(), 0.0);
+        assert_eq!(start.points().len(), 5);
+        assert_eq!(start.area(), square.area());
+        let middle = square.clone().lerp(pentagon.clone(), 0.5);
+        assert_eq!(middle.points().len(), 5);
+        assert!(middle.area() > square.area() && middle.area() < pentagon.area());
+        assert!((square.inv_lerp(pentagon, middle) - 0.5).abs() < 0.05);
+
+        // opposite winding is reversed rather than turning inside out
+        let clockwise = Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10)]);
+        let counter = Polygon::new(&[(20, 0), (20, 10), (30, 10), (30, 0)]);
+        let middle = clockwise.lerp(counter, 0.5);
+        assert_eq!(middle.area(), 100.0);
+    }
+
+    #[test]
+    fn shape_box() {
+        let circle = ShapeBox::from(Circle::new((0, 0), 10));
+        let bigger = ShapeBox::from(Circle::new((0, 0), 20));
+        assert_eq!(
+            circle.clone().lerp(bigger, 0.5),
+            ShapeBox::Circle(Circle::new((0, 0), 15))
+        );
+
+        let rect = ShapeBox::from(Rect::new((-10, -10), (10, 10)));
+        let start = circle.clone().lerp(rect.clone(), 0.0);
+        let end = circle.lerp(rect.clone(), 1.0);
+        assert!(matches!(start, ShapeBox::Polygon(_)));
+        assert_eq!(
+            start,
+            ShapeBox::Polygon(Circle::new((0, 0), 10).as_polygon())
+        );
+        assert_eq!(end.left(), -10);
+        assert_eq!(end.bottom(), 10);
+        assert!((end.outline_length() - 80.0).abs() < 0.1);
+    }
 }
@@ -20,35 +20,61 @@
 
 use crate::coord::Coord;
 use crate::general_math::{rotate_points, scale_points};
+use crate::outline::Outline;
 use crate::prelude::*;
 use crate::shape_box::ShapeBox;
+use crate::shape_group::ShapeGroup;
 use fnv::FnvHashSet;
 use std::any::Any;
 
+pub mod annulus;
+pub mod capsule;
 pub mod circle;
+pub mod collision;
 #[macro_use]
 pub mod coord;
 pub mod contains;
+mod distance;
+pub mod distance_field;
+pub mod easing;
 pub mod ellipse;
 pub mod general_math;
 pub mod intersection;
 pub mod lerp;
 pub mod line;
+mod outline;
 pub mod polygon;
+pub mod polygon_with_holes;
+pub mod polyline;
+pub mod ray;
 pub mod rect;
+#[cfg(feature = "rand")]
+pub mod sampling;
 pub mod shape_box;
+pub mod shape_group;
+pub mod spline;
+pub mod tessellation;
 pub mod triangle;
+pub mod visibility;
 
 pub mod prelude {
+    pub use crate::annulus::*;
+    pub use crate::capsule::*;
     pub use crate::circle::*;
+    pub use crate::collision::*;
     pub use crate::contains::ContainsShape;
     pub use crate::coord;
     pub use crate::coord::*;
+    pub use crate::distance_field::*;
+    pub use crate::easing::*;
     pub use crate::ellipse::*;
     pub use crate::intersection::IntersectsShape;
     pub use crate::lerp::*;
     pub use crate::line::*;
     pub use crate::polygon::*;
+    pub use crate::polygon_with_holes::*;
+    pub use crate::polyline::*;
+    pub use crate::ray::*;
     pub use crate::rect::*;
     pub use crate::triangle::*;
     pub use crate::IntersectsContains;
@@ -220,6 +246,110 @@ pub trait Shape: AnyToAny {
     #[must_use]
     fn filled_pixels(&self) -> Vec<Coord>;
 
+    /// Smallest convex polygon that contains the shape, see [Polygon::convex_hull]
+    ///
+    /// Curved shapes are approximated
+    #[must_use]
+    fn convex_hull(&self) -> Polygon {
+        Polygon::convex_hull(&self.points())
+    }
+
+    /// Polygon covering every point within `distance` of the shape, with rounded corners
+    ///
+    /// Useful for outlines, hit-slop margins, etc. By default this grows the [convex_hull](Shape::convex_hull)
+    #[must_use]
+    fn buffer(&self, distance: usize) -> Polygon {
+        let hull = self.convex_hull();
+        hull.offset(distance as isize, Join::Round)
+            .pop()
+            .unwrap_or(hull)
+    }
+
+    /// Distance from `point` to the outline of the shape, negative if `point` is inside the shape
+    ///
+    /// Lines don't have an inside so this is never negative for them
+    #[must_use]
+    fn distance_to_point(&self, point: Coord) -> f32 {
+        distance::distance_to_point(&self.to_shape_box(), point)
+    }
+
+    /// Signed distance from `point` to the outline, negative inside, used for distance fields
+    ///
+    /// All the shapes in this crate override this to calculate the distance directly from the shape
+    /// without allocating, so it's cheap enough to call per pixel, see [DistanceField](distance_field::DistanceField).
+    /// The default falls back to [distance_to_point](Shape::distance_to_point), which converts
+    /// the shape to a [ShapeBox] first
+    #[must_use]
+    fn signed_distance(&self, point: Coord) -> f32 {
+        self.distance_to_point(point)
+    }
+
+    /// Shortest distance between this shape and `other`, 0 if they overlap
+    #[must_use]
+    fn distance_to(&self, other: &dyn Shape) -> f32 {
+        let (first, second) = distance::closest_points(&self.to_shape_box(), &other.to_shape_box());
+        ((second.0 - first.0).powi(2) + (second.1 - first.1).powi(2)).sqrt()
+    }
+
+    /// The point on this shape closest to `other` and the point on `other` closest to this shape
+    ///
+    /// If the shapes overlap both points are the same point inside both shapes
+    #[must_use]
+    fn closest_points(&self, other: &dyn Shape) -> (Coord, Coord) {
+        let (first, second) = distance::closest_points(&self.to_shape_box(), &other.to_shape_box());
+        (distance::round(first), distance::round(second))
+    }
+
+    /// First point where `ray` hits the outline of the shape, see [Ray]
+    ///
+    /// Rays that start inside a shape hit the outline on the way out
+    #[must_use]
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        ray::raycast(&self.to_shape_box(), ray)
+    }
+
+    /// Length of the outline, circles and ellipses are measured along the curve
+    ///
+    /// For groups this is the total of all the children
+    #[must_use]
+    fn outline_length(&self) -> f32 {
+        Outline::new(&self.to_shape_box()).length()
+    }
+
+    /// Point `distance` pixels along the outline
+    ///
+    /// Closed shapes go clockwise, wrapping around for distances past the end or below zero.
+    /// They start at:
+    /// * Rects, triangles and polygons: the top most (then left most) corner
+    /// * Circles: the top
+    /// * Ellipses: their top, rotated with the ellipse
+    /// * Capsules: the corner of the side on the left going from start to end
+    ///
+    /// Lines go from start to end and are clamped. Annuluses and polygons with holes only
+    /// use their outer outline and groups go through each shape in order
+    #[must_use]
+    fn point_at_distance(&self, distance: f32) -> Coord {
+        Outline::new(&self.to_shape_box()).point_at_distance(distance)
+    }
+
+    /// Point `fraction` of the way along the outline, where `0.0` is the start and `1.0` is the end
+    ///
+    /// See [Shape::point_at_distance]
+    #[must_use]
+    fn point_at_fraction(&self, fraction: f32) -> Coord {
+        let outline = Outline::new(&self.to_shape_box());
+        outline.point_at_distance(outline.length() * fraction)
+    }
+
+    /// Direction of travel (as a unit vector) `fraction` of the way along the outline
+    ///
+    /// See [Shape::point_at_fraction]
+    #[must_use]
+    fn tangent_at(&self, fraction: f32) -> (f32, f32) {
+        let outline = Outline::new(&self.to_shape_box());
+        outline.tangent_at_distance(outline.length() * fraction)
+    }
+
     /// Convert to [ShapeBox], used to store shapes with type (for bulk drawing, etc)
     #[must_use]
     fn to_shape_box(&self) -> ShapeBox;
@@ -251,6 +381,18 @@ pub trait IntersectsContains: Shape + ContainsShape + IntersectsShape + Sized {
         if let Some(ellipse) = other.as_any().downcast_ref::<Ellipse>() {
             return Some(self.contains_ellipse(ellipse));
         }
+        if let Some(annulus) = other.as_any().downcast_ref::<Annulus>() {
+            return Some(self.contains_annulus(annulus));
+        }
+        if let Some(capsule) = other.as_any().downcast_ref::<Capsule>() {
+            return Some(self.contains_capsule(capsule));
+        }
+        if let Some(polygon) = other.as_any().downcast_ref::<PolygonWithHoles>() {
+            return Some(self.contains_polygon_with_holes(polygon));
+        }
+        if let Some(group) = other.as_any().downcast_ref::<ShapeGroup>() {
+            return Some(self.contains_group(group));
+        }
         if let Some(shapebox) = other.as_any().downcast_ref::<ShapeBox>() {
             return Some(match shapebox {
                 ShapeBox::Line(line) => self.contains_line(line),
@@ -259,6 +401,10 @@ pub trait IntersectsContains: Shape + ContainsShape + IntersectsShape + Sized {
                 ShapeBox::Circle(circle) => self.contains_circle(circle),
                 ShapeBox::Ellipse(ellipse) => self.contains_ellipse(ellipse),
                 ShapeBox::Polygon(polygon) => self.contains_polygon(polygon),
+                ShapeBox::Annulus(annulus) => self.contains_annulus(annulus),
+                ShapeBox::Capsule(capsule) => self.contains_capsule(capsule),
+                ShapeBox::PolygonWithHoles(polygon) => self.contains_polygon_with_holes(polygon),
+                ShapeBox::Group(group) => self.contains_group(group),
             });
         }
         None
@@ -288,6 +434,18 @@ pub trait IntersectsContains: Shape + ContainsShape + IntersectsShape + Sized {
         if let Some(ellipse) = other.as_any().downcast_ref::<Ellipse>() {
             return Some(self.intersects_ellipse(ellipse));
         }
+        if let Some(annulus) = other.as_any().downcast_ref::<Annulus>() {
+            return Some(self.intersects_annulus(annulus));
+        }
+        if let Some(capsule) = other.as_any().downcast_ref::<Capsule>() {
+            return Some(self.intersects_capsule(capsule));
+        }
+        if let Some(polygon) = other.as_any().downcast_ref::<PolygonWithHoles>() {
+            return Some(self.intersects_polygon_with_holes(polygon));
+        }
+        if let Some(group) = other.as_any().downcast_ref::<ShapeGroup>() {
+            return Some(self.intersects_group(group));
+        }
         if let Some(shapebox) = other.as_any().downcast_ref::<ShapeBox>() {
             return Some(match shapebox {
                 ShapeBox::Line(line) => self.intersects_line(line),
@@ -296,10 +454,126 @@ pub trait IntersectsContains: Shape + ContainsShape + IntersectsShape + Sized {
                 ShapeBox::Circle(circle) => self.intersects_circle(circle),
                 ShapeBox::Ellipse(ellipse) => self.intersects_ellipse(ellipse),
                 ShapeBox::Polygon(polygon) => self.intersects_polygon(polygon),
+                ShapeBox::Annulus(annulus) => self.intersects_annulus(annulus),
+                ShapeBox::Capsule(capsule) => self.intersects_capsule(capsule),
+                ShapeBox::PolygonWithHoles(polygon) => self.intersects_polygon_with_holes(polygon),
+                ShapeBox::Group(group) => self.intersects_group(group),
             });
         }
         None
     }
+
+    /// Returns true if `self` contains every shape in `group`
+    #[must_use]
+    fn contains_group(&self, group: &ShapeGroup) -> bool {
+        group
+            .shapes()
+            .iter()
+            .all(|shape| self.contains_shape(shape) == Some(true))
+    }
+
+    /// Returns true if `self` intersects any shape in `group`
+    #[must_use]
+    fn intersects_group(&self, group: &ShapeGroup) -> bool {
+        group
+            .shapes()
+            .iter()
+            .any(|shape| self.intersects_shape(shape) == Some(true))
+    }
+
+    /// Returns
+    /// * Some(true) if `self` and `other` share any area (their outlines cross or one contains the other)
+    /// * Some(false) if `self` and `other` are separate
+    /// * None if `other` isn't a supported `Shape`
+    ///
+    /// Unlike [intersects_shape](IntersectsContains::intersects_shape) this is true if one shape
+    /// is completely inside the other
+    #[must_use]
+    fn overlaps_shape(&self, other: &dyn Shape) -> Option<bool> {
+        let intersects = self.intersects_shape(other)?;
+        let contains = self.contains_shape(other)?;
+        Some(intersects || contains || other.to_shape_box().contains_shape(self) == Some(true))
+    }
+
+    /// Returns true if `self` and `rect` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_rect(&self, rect: &Rect) -> bool {
+        self.intersects_rect(rect)
+            || self.contains_rect(rect)
+            || rect.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `circle` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_circle(&self, circle: &Circle) -> bool {
+        self.intersects_circle(circle)
+            || self.contains_circle(circle)
+            || circle.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `line` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_line(&self, line: &Line) -> bool {
+        self.intersects_line(line)
+            || self.contains_line(line)
+            || line.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `triangle` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_triangle(&self, triangle: &Triangle) -> bool {
+        self.intersects_triangle(triangle)
+            || self.contains_triangle(triangle)
+            || triangle.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `ellipse` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_ellipse(&self, ellipse: &Ellipse) -> bool {
+        self.intersects_ellipse(ellipse)
+            || self.contains_ellipse(ellipse)
+            || ellipse.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `polygon` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_polygon(&self, polygon: &Polygon) -> bool {
+        self.intersects_polygon(polygon)
+            || self.contains_polygon(polygon)
+            || polygon.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `annulus` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_annulus(&self, annulus: &Annulus) -> bool {
+        self.intersects_annulus(annulus)
+            || self.contains_annulus(annulus)
+            || annulus.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `capsule` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_capsule(&self, capsule: &Capsule) -> bool {
+        self.intersects_capsule(capsule)
+            || self.contains_capsule(capsule)
+            || capsule.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `polygon` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        self.intersects_polygon_with_holes(polygon)
+            || self.contains_polygon_with_holes(polygon)
+            || polygon.contains_shape(self) == Some(true)
+    }
+
+    /// Returns true if `self` and `group` share any area, see [overlaps_shape](IntersectsContains::overlaps_shape)
+    #[must_use]
+    fn overlaps_group(&self, group: &ShapeGroup) -> bool {
+        self.intersects_group(group)
+            || self.contains_group(group)
+            || group.contains_shape(self) == Some(true)
+    }
 }
 
 fn new_hash_set() -> FnvHashSet<Coord> {
@@ -345,6 +619,59 @@ mod test {
         assert_eq!(outer.contains_shape(&outside), Some(false));
     }
 
+    #[test]
+    fn overlaps() {
+        let outer = Rect::new((10, 10), (40, 40));
+        let inner = Rect::new((20, 20), (30, 30));
+        let crossing = Rect::new((35, 20), (50, 30));
+        let separate = Rect::new((50, 50), (60, 60));
+        assert!(!outer.intersects_rect(&inner));
+        assert!(outer.overlaps_rect(&inner));
+        assert!(inner.overlaps_rect(&outer));
+        assert!(outer.overlaps_rect(&crossing));
+        assert!(!outer.overlaps_rect(&separate));
+
+        let circle = Circle::new((25, 25), 3);
+        assert!(outer.overlaps_circle(&circle));
+        assert!(circle.overlaps_rect(&outer));
+        assert!(!circle.overlaps_rect(&separate));
+
+        let line = Line::new((22, 22), (28, 28));
+        assert!(line.overlaps_rect(&inner));
+        assert!(inner.overlaps_line(&line));
+        assert!(!line.overlaps_rect(&separate));
+
+        let triangle = Triangle::new((0, 0), (100, 0), (0, 100));
+        assert!(triangle.overlaps_rect(&inner));
+        assert!(inner.overlaps_triangle(&triangle));
+        assert!(!triangle.overlaps_rect(&Rect::new((80, 80), (90, 90))));
+
+        let ellipse = Ellipse::new((25, 25), 60, 40);
+        assert!(ellipse.overlaps_circle(&circle));
+        assert!(circle.overlaps_ellipse(&ellipse));
+
+        let polygon = Polygon::regular((25, 25), 4, 6, 0);
+        assert!(polygon.overlaps_rect(&outer));
+        assert!(outer.overlaps_polygon(&polygon));
+        assert!(!polygon.overlaps_rect(&separate));
+    }
+
+    #[test]
+    fn overlaps_shape() {
+        let outer = Rect::new((10, 10), (40, 40));
+        let inner = Circle::new((25, 25), 3);
+        let separate = Triangle::new((50, 50), (60, 50), (50, 60));
+        assert_eq!(outer.overlaps_shape(&inner), Some(true));
+        assert_eq!(inner.overlaps_shape(&outer), Some(true));
+        assert_eq!(inner.overlaps_shape(&outer.to_shape_box()), Some(true));
+        assert_eq!(inner.to_shape_box().overlaps_shape(&outer), Some(true));
+        assert!(outer
+            .to_shape_box()
+            .overlaps_rect(&Rect::new((20, 20), (30, 30))));
+        assert_eq!(outer.overlaps_shape(&separate), Some(false));
+        assert_eq!(separate.to_shape_box().overlaps_shape(&outer), Some(false));
+    }
+
     #[test]
     fn shapebox_intersects() {
         let line = Line::new((10, 10), (20, 20));
@@ -1,3 +1,4 @@
+use crate::general_math::distance_to_segment;
 use crate::prelude::*;
 use crate::shape_box::ShapeBox;
 #[cfg(feature = "serde")]
@@ -103,6 +104,24 @@ impl Line {
             self.start.y as f64 + t * ba_y
         )
     }
+
+    /// Percent along the line of the closest point to `point`, where `0.0` is the start and
+    /// `1.0` is the end
+    ///
+    /// Unlike [Lerp::inv_lerp] for coords, the result is clamped to `0.0..=1.0`.
+    /// Returns `0.0` if the start and end are the same
+    ///
+    /// # Usage
+    /// ```rust
+    ///# use graphics_shapes::prelude::*;
+    /// let line = Line::new((10, 0), (10, 20));
+    /// assert_eq!(line.parameter_of((3, 5)), 0.25);
+    /// assert_eq!(line.parameter_of((10, 40)), 1.0);
+    /// ```
+    #[must_use]
+    pub fn parameter_of<P: Into<Coord>>(&self, point: P) -> f32 {
+        self.start.inv_lerp(self.end, point.into()).clamp(0.0, 1.0)
+    }
 }
 
 impl Shape for Line {
@@ -252,6 +271,16 @@ impl Shape for Line {
         self.outline_pixels()
     }
 
+    /// Capsule shaped polygon
+    fn buffer(&self, distance: usize) -> Polygon {
+        Capsule::new(self.start(), self.end(), distance).as_polygon()
+    }
+
+    /// Lines don't have an inside so this is never negative
+    fn signed_distance(&self, point: Coord) -> f32 {
+        distance_to_segment(point, self.start, self.end)
+    }
+
     fn to_shape_box(&self) -> ShapeBox {
         ShapeBox::Line(self.clone())
     }
@@ -314,6 +343,26 @@ mod test {
         assert_eq!(point, coord!(75, 65));
     }
 
+    #[test]
+    fn parameter_of() {
+        use crate::lerp::Lerp;
+
+        let horizontal = Line::new((0, 5), (40, 5));
+        assert_eq!(horizontal.parameter_of((10, 5)), 0.25);
+        assert_eq!(horizontal.parameter_of((10, 100)), 0.25);
+        assert_eq!(horizontal.parameter_of((-10, 5)), 0.0);
+        let vertical = Line::new((3, 20), (3, 0));
+        assert_eq!(vertical.parameter_of((3, 15)), 0.25);
+        assert_eq!(vertical.parameter_of((50, -5)), 1.0);
+        let diagonal = Line::new((110, 100), (40, 30));
+        let percent = diagonal.parameter_of((55, 85));
+        assert_eq!(
+            diagonal.start().lerp(diagonal.end(), percent),
+            diagonal.nearest_point((55, 85))
+        );
+        assert_eq!(Line::new((7, 7), (7, 7)).parameter_of((0, 0)), 0.0);
+    }
+
     mod contains {
         use crate::line::Line;
         use crate::Shape;
@@ -0,0 +1,393 @@
+//! Positions along the outline of shapes, see [crate::Shape::point_at_distance]
+//!
+//! Closed outlines go clockwise and wrap around so distances past the end (or negative) continue
+//! around the outline, see [crate::Shape::point_at_distance] for where each shape starts
+
+use crate::distance::{add, fcoord, length, normalise, round, scale, sub, Point};
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// How many points are used to measure the length along an ellipse
+const ELLIPSE_STEPS: usize = 256;
+
+/// Part of an outline
+#[derive(Debug, Clone)]
+enum Piece {
+    Segment(Point, Point),
+    /// Circular arc, angles are in radians (0 is right, increasing is clockwise)
+    Arc {
+        center: Point,
+        radius: f32,
+        start: f32,
+        sweep: f32,
+    },
+    /// Full ellipse starting from the top, `lengths` is the distance along the outline at each step
+    Ellipse {
+        center: Point,
+        radii: Point,
+        rotation: f32,
+        lengths: Vec<f32>,
+    },
+}
+
+impl Piece {
+    fn length(&self) -> f32 {
+        match self {
+            Piece::Segment(start, end) => length(sub(*end, *start)),
+            Piece::Arc { radius, sweep, .. } => radius * sweep.abs(),
+            Piece::Ellipse { lengths, .. } => lengths.last().copied().unwrap_or_default(),
+        }
+    }
+
+    /// Point and direction of travel `distance` along the piece
+    fn at(&self, distance: f32) -> (Point, Point) {
+        match self {
+            Piece::Segment(start, end) => {
+                let len = length(sub(*end, *start));
+                let amount = if len == 0.0 { 0.0 } else { distance / len };
+                (
+                    add(*start, scale(sub(*end, *start), amount)),
+                    normalise(sub(*end, *start)),
+                )
+            }
+            Piece::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            } => {
+                let turn = if *radius == 0.0 {
+                    0.0
+                } else {
+                    distance / radius
+                };
+                let angle = start + turn * sweep.signum();
+                let (sin, cos) = angle.sin_cos();
+                (
+                    add(*center, (cos * radius, sin * radius)),
+                    scale((-sin, cos), sweep.signum()),
+                )
+            }
+            Piece::Ellipse {
+                center,
+                radii,
+                rotation,
+                lengths,
+            } => {
+                // find the angle for `distance` using the measured lengths
+                let step = lengths
+                    .partition_point(|length| *length <= distance)
+                    .clamp(1, lengths.len() - 1);
+                let (before, after) = (lengths[step - 1], lengths[step]);
+                let part = if after > before {
+                    (distance - before) / (after - before)
+                } else {
+                    0.0
+                };
+                let angle = ((step - 1) as f32 + part) / ELLIPSE_STEPS as f32 * TAU;
+                ellipse_at(*center, *radii, *rotation, angle)
+            }
+        }
+    }
+}
+
+/// Point and direction of travel at `angle` radians clockwise from the top of the ellipse
+fn ellipse_at(center: Point, radii: Point, rotation: f32, angle: f32) -> (Point, Point) {
+    let (sin, cos) = (angle - FRAC_PI_2).sin_cos();
+    let (rot_sin, rot_cos) = rotation.sin_cos();
+    let rotate = |(x, y): Point| (x * rot_cos - y * rot_sin, x * rot_sin + y * rot_cos);
+    (
+        add(center, rotate((radii.0 * cos, radii.1 * sin))),
+        normalise(rotate((-radii.0 * sin, radii.1 * cos))),
+    )
+}
+
+/// Outline of a shape split into pieces, see [Shape::point_at_distance]
+pub(crate) struct Outline {
+    pieces: Vec<Piece>,
+    closed: bool,
+    fallback: Coord,
+}
+
+impl Outline {
+    pub(crate) fn new(shape: &ShapeBox) -> Self {
+        Outline {
+            pieces: pieces(shape),
+            closed: !matches!(shape, ShapeBox::Line(_)),
+            fallback: shape.center(),
+        }
+    }
+
+    pub(crate) fn length(&self) -> f32 {
+        self.pieces.iter().map(Piece::length).sum()
+    }
+
+    pub(crate) fn point_at_distance(&self, distance: f32) -> Coord {
+        self.at(distance)
+            .map(|(point, _)| round(point))
+            .unwrap_or(self.fallback)
+    }
+
+    pub(crate) fn tangent_at_distance(&self, distance: f32) -> (f32, f32) {
+        self.at(distance)
+            .map(|(_, tangent)| tangent)
+            .unwrap_or_default()
+    }
+
+    fn at(&self, distance: f32) -> Option<(Point, Point)> {
+        let total = self.length();
+        let last = self.pieces.last()?;
+        if total <= 0.0 {
+            return Some(self.pieces[0].at(0.0));
+        }
+        let mut distance = if self.closed {
+            distance.rem_euclid(total)
+        } else {
+            distance.clamp(0.0, total)
+        };
+        for piece in &self.pieces {
+            let length = piece.length();
+            if distance < length {
+                return Some(piece.at(distance));
+            }
+            distance -= length;
+        }
+        Some(last.at(last.length()))
+    }
+}
+
+fn pieces(shape: &ShapeBox) -> Vec<Piece> {
+    let lines = |lines: &[Line]| -> Vec<Piece> {
+        lines
+            .iter()
+            .map(|line| Piece::Segment(fcoord(line.start()), fcoord(line.end())))
+            .collect()
+    };
+    let circle = |center: Coord, radius: usize| Piece::Arc {
+        center: fcoord(center),
+        radius: radius as f32,
+        start: -FRAC_PI_2,
+        sweep: TAU,
+    };
+    match shape {
+        ShapeBox::Line(line) => lines(std::slice::from_ref(line)),
+        ShapeBox::Rect(rect) => lines(&rect.as_polygon().as_lines()),
+        ShapeBox::Triangle(triangle) => {
+            lines(&clockwise_from_top(&Polygon::new(&triangle.points())))
+        }
+        ShapeBox::Circle(shape) => vec![circle(shape.center(), shape.radius())],
+        ShapeBox::Ellipse(ellipse) => vec![ellipse_piece(ellipse)],
+        ShapeBox::Polygon(polygon) => lines(&clockwise_from_top(polygon)),
+        ShapeBox::Annulus(annulus) => vec![circle(annulus.center(), annulus.outer_radius())],
+        ShapeBox::Capsule(capsule) => capsule_pieces(capsule),
+        ShapeBox::PolygonWithHoles(polygon) => lines(&clockwise_from_top(polygon.outer())),
+        ShapeBox::Group(group) => group.shapes().iter().flat_map(pieces).collect(),
+    }
+}
+
+/// Sides of `polygon` going clockwise, starting from the top most (then left most) corner
+fn clockwise_from_top(polygon: &Polygon) -> Vec<Line> {
+    let mut lines = polygon.to_clockwise().as_lines();
+    if let Some(first) = lines
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, line)| (line.start().y, line.start().x))
+        .map(|(i, _)| i)
+    {
+        lines.rotate_left(first);
+    }
+    lines
+}
+
+fn ellipse_piece(ellipse: &Ellipse) -> Piece {
+    let center = fcoord(ellipse.center());
+    let radii = ellipse.radii();
+    let rotation = (ellipse.angle() as f32).to_radians();
+    let mut lengths = Vec::with_capacity(ELLIPSE_STEPS + 1);
+    let mut total = 0.0;
+    let mut previous = ellipse_at(center, radii, rotation, 0.0).0;
+    lengths.push(0.0);
+    for i in 1..=ELLIPSE_STEPS {
+        let point = ellipse_at(
+            center,
+            radii,
+            rotation,
+            i as f32 / ELLIPSE_STEPS as f32 * TAU,
+        )
+        .0;
+        total += length(sub(point, previous));
+        lengths.push(total);
+        previous = point;
+    }
+    Piece::Ellipse {
+        center,
+        radii,
+        rotation,
+        lengths,
+    }
+}
+
+/// Side, curve around the end, other side, curve around the start
+fn capsule_pieces(capsule: &Capsule) -> Vec<Piece> {
+    let start = fcoord(capsule.start());
+    let end = fcoord(capsule.end());
+    let radius = capsule.radius() as f32;
+    let spine = sub(end, start);
+    let angle = spine.1.atan2(spine.0);
+    let side = scale((angle.sin(), -angle.cos()), radius);
+    vec![
+        Piece::Segment(add(start, side), add(end, side)),
+        Piece::Arc {
+            center: end,
+            radius,
+            start: angle - FRAC_PI_2,
+            sweep: PI,
+        },
+        Piece::Segment(sub(end, side), sub(start, side)),
+        Piece::Arc {
+            center: start,
+            radius,
+            start: angle + FRAC_PI_2,
+            sweep: PI,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::shape_box::ShapeBox;
+    use crate::shape_group::ShapeGroup;
+
+    fn close(actual: (f32, f32), expected: (f32, f32)) -> bool {
+        (actual.0 - expected.0).abs() < 0.01 && (actual.1 - expected.1).abs() < 0.01
+    }
+
+    #[test]
+    fn line() {
+        let line = Line::new((0, 0), (30, 40));
+        assert_eq!(line.outline_length(), 50.0);
+        assert_eq!(line.point_at_distance(25.0), coord!(15, 20));
+        assert_eq!(line.point_at_fraction(0.2), coord!(6, 8));
+        assert_eq!(line.point_at_distance(100.0), coord!(30, 40));
+        assert_eq!(line.point_at_distance(-10.0), coord!(0, 0));
+        assert!(close(line.tangent_at(0.5), (0.6, 0.8)));
+    }
+
+    #[test]
+    fn rect() {
+        let rect = Rect::new((0, 0), (20, 10));
+        assert_eq!(rect.outline_length(), 60.0);
+        assert_eq!(rect.point_at_distance(0.0), coord!(0, 0));
+        assert_eq!(rect.point_at_distance(25.0), coord!(20, 5));
+        assert_eq!(rect.point_at_distance(40.0), coord!(10, 10));
+        assert_eq!(rect.point_at_distance(65.0), coord!(5, 0));
+        assert_eq!(rect.point_at_distance(-5.0), coord!(0, 5));
+        assert_eq!(rect.point_at_fraction(0.5), coord!(20, 10));
+        assert!(close(rect.tangent_at(0.1), (1.0, 0.0)));
+        assert!(close(rect.tangent_at(0.4), (0.0, 1.0)));
+        assert!(close(rect.tangent_at(0.6), (-1.0, 0.0)));
+    }
+
+    #[test]
+    fn polygon_and_triangle() {
+        let triangle = Triangle::new((0, 0), (30, 0), (30, 40));
+        assert_eq!(triangle.outline_length(), 120.0);
+        assert_eq!(triangle.point_at_distance(50.0), coord!(30, 20));
+        assert_eq!(triangle.point_at_fraction(0.75), coord!(18, 24));
+        let polygon = Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10)]);
+        assert_eq!(polygon.outline_length(), 40.0);
+        assert_eq!(polygon.point_at_distance(15.0), coord!(10, 5));
+    }
+
+    #[test]
+    fn polygons_start_at_top_and_go_clockwise() {
+        let triangle = Triangle::new((0, 10), (10, 10), (5, 0));
+        assert_eq!(triangle.point_at_distance(0.0), coord!(5, 0));
+        assert!(triangle.tangent_at(0.01).0 > 0.0);
+        assert_eq!(triangle.point_at_distance(-1.0).y, 1);
+        assert!(triangle.tangent_at(0.99).1 < 0.0);
+
+        let counter_clockwise = Polygon::new(&[(0, 10), (10, 10), (10, 0), (0, 0)]);
+        assert_eq!(counter_clockwise.point_at_distance(0.0), coord!(0, 0));
+        assert_eq!(counter_clockwise.point_at_distance(5.0), coord!(5, 0));
+        assert_eq!(counter_clockwise.point_at_distance(15.0), coord!(10, 5));
+    }
+
+    #[test]
+    fn circle() {
+        let circle = Circle::new((0, 0), 10);
+        assert!((circle.outline_length() - 62.83).abs() < 0.01);
+        assert_eq!(circle.point_at_fraction(0.0), coord!(0, -10));
+        assert_eq!(circle.point_at_fraction(0.25), coord!(10, 0));
+        assert_eq!(circle.point_at_fraction(0.5), coord!(0, 10));
+        assert_eq!(circle.point_at_fraction(0.75), coord!(-10, 0));
+        assert_eq!(
+            circle.point_at_distance(circle.outline_length()),
+            coord!(0, -10)
+        );
+        assert!(close(circle.tangent_at(0.0), (1.0, 0.0)));
+        assert!(close(circle.tangent_at(0.25), (0.0, 1.0)));
+    }
+
+    #[test]
+    fn ellipse() {
+        let ellipse = Ellipse::new((0, 0), 40, 20);
+        // Ramanujan's approximation
+        let (a, b) = (20.0_f32, 10.0_f32);
+        let h = ((a - b) / (a + b)).powi(2);
+        let expected =
+            std::f32::consts::PI * (a + b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()));
+        assert!((ellipse.outline_length() - expected).abs() < 0.1);
+        assert_eq!(ellipse.point_at_fraction(0.0), coord!(0, -10));
+        assert_eq!(ellipse.point_at_fraction(0.25), coord!(20, 0));
+        assert_eq!(ellipse.point_at_fraction(0.5), coord!(0, 10));
+        assert!(close(ellipse.tangent_at(0.25), (0.0, 1.0)));
+
+        // evenly spaced by distance, not by angle
+        let points: Vec<Coord> = (0..40)
+            .map(|i| ellipse.point_at_fraction(i as f32 / 40.0))
+            .collect();
+        let gaps: Vec<usize> = (0..40)
+            .map(|i| points[i].distance(points[(i + 1) % 40]))
+            .collect();
+        assert!(gaps.iter().all(|gap| (2..=3).contains(gap)), "{gaps:?}");
+    }
+
+    #[test]
+    fn capsule() {
+        let capsule = Capsule::new((0, 0), (20, 0), 5);
+        let half_circle = std::f32::consts::PI * 5.0;
+        assert!((capsule.outline_length() - (40.0 + 2.0 * half_circle)).abs() < 0.01);
+        assert_eq!(capsule.point_at_distance(0.0), coord!(0, -5));
+        assert_eq!(capsule.point_at_distance(10.0), coord!(10, -5));
+        assert_eq!(
+            capsule.point_at_distance(20.0 + half_circle / 2.0),
+            coord!(25, 0)
+        );
+        assert_eq!(capsule.point_at_distance(30.0 + half_circle), coord!(10, 5));
+        assert_eq!(
+            capsule.point_at_distance(40.0 + half_circle * 1.5),
+            coord!(-5, 0)
+        );
+    }
+
+    #[test]
+    fn others() {
+        let annulus = Annulus::new((0, 0), 5, 10);
+        assert_eq!(annulus.point_at_fraction(0.25), coord!(10, 0));
+        let group = ShapeGroup::new(&[
+            ShapeBox::from(Line::new((0, 0), (10, 0))),
+            ShapeBox::from(Line::new((0, 10), (10, 10))),
+        ]);
+        assert_eq!(group.outline_length(), 20.0);
+        assert_eq!(group.point_at_distance(15.0), coord!(5, 10));
+        let boxed = ShapeBox::from(Circle::new((0, 0), 10));
+        assert_eq!(boxed.point_at_fraction(0.25), coord!(10, 0));
+        assert_eq!(
+            Polygon::new::<Coord>(&[]).point_at_fraction(0.5),
+            coord!(0, 0)
+        );
+    }
+}
@@ -1,7 +1,70 @@
+use crate::general_math::{polygon_signed_distance, simplify_rdp, simplify_visvalingam};
 use crate::prelude::*;
 use crate::shape_box::ShapeBox;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// How corners are drawn when offsetting a polygon, see [Polygon::offset]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
+pub enum Join {
+    /// Extend the sides until they meet, falls back to [Join::Bevel] for very sharp corners
+    Miter,
+    /// Corners are rounded using several short lines
+    Round,
+    /// Corners are cut off with a single line
+    Bevel,
+}
+
+/// Direction the points of a polygon go around its center
+///
+/// This is as seen on screen, where y increases downwards, so it's the opposite of the
+/// winding in maths (where y increases upwards)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Problems found by [Polygon::validate]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum PolygonError {
+    /// Less than three points, contains the number of points
+    TooFewPoints(usize),
+    /// A point is the same as the next point, contains the index of the first one
+    DuplicatePoint(usize),
+    /// A point is on the line between the previous and next points, contains the index of the point
+    CollinearPoint(usize),
+    /// The polygon has no area
+    ZeroArea,
+    /// Two sides cross or touch each other, contains the index of the first point of each side
+    SelfIntersection(usize, usize),
+}
+
+impl Display for PolygonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PolygonError::TooFewPoints(count) => {
+                write!(f, "polygon needs at least 3 points, found {count}")
+            }
+            PolygonError::DuplicatePoint(idx) => {
+                write!(f, "point {idx} is the same as the next point")
+            }
+            PolygonError::CollinearPoint(idx) => {
+                write!(f, "point {idx} is on the line between its neighbours")
+            }
+            PolygonError::ZeroArea => write!(f, "polygon has no area"),
+            PolygonError::SelfIntersection(first, second) => {
+                write!(f, "side {first} crosses side {second}")
+            }
+        }
+    }
+}
+
+impl Error for PolygonError {}
 
 /// Shape with any number of points/line
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -32,11 +95,96 @@ impl Polygon {
             is_regular: false,
             is_convex,
         };
-        poly.center = poly.top_left().mid_point(poly.bottom_right());
-        let dists: Vec<usize> = points.iter().map(|p| p.distance(poly.center)).collect();
-        poly.is_regular = dists.iter().all(|dist| dist == &dists[0]);
+        if !points.is_empty() {
+            poly.center = poly.top_left().mid_point(poly.bottom_right());
+        }
+        poly.is_regular = is_convex && is_regular(&points);
         poly
     }
+
+    /// Create a regular polygon (all sides and angles are equal) with `sides` corners,
+    /// each `radius` away from `center`
+    ///
+    /// With a `rotation` of 0 the first corner is directly above `center`
+    #[must_use]
+    pub fn regular<P: Into<Coord>>(
+        center: P,
+        radius: usize,
+        sides: usize,
+        rotation: isize,
+    ) -> Self {
+        debug_assert!(sides >= 3);
+        let center = center.into();
+        let step = 360.0 / sides as f64;
+        let points: Vec<Coord> = (0..sides)
+            .map(|i| point_on_circle(center, radius as f64, rotation as f64 + step * i as f64))
+            .collect();
+        Polygon::new(&points)
+    }
+
+    /// Create a star with `points` tips `outer_radius` away from `center` and the inner corners
+    /// `inner_radius` away from `center`
+    ///
+    /// With a `rotation` of 0 the first tip is directly above `center`
+    #[must_use]
+    pub fn star<P: Into<Coord>>(
+        center: P,
+        outer_radius: usize,
+        inner_radius: usize,
+        points: usize,
+        rotation: isize,
+    ) -> Self {
+        debug_assert!(points >= 2);
+        let center = center.into();
+        let step = 180.0 / points as f64;
+        let corners: Vec<Coord> = (0..points * 2)
+            .map(|i| {
+                let radius = if i % 2 == 0 {
+                    outer_radius
+                } else {
+                    inner_radius
+                };
+                point_on_circle(center, radius as f64, rotation as f64 + step * i as f64)
+            })
+            .collect();
+        Polygon::new(&corners)
+    }
+
+    /// Create the smallest convex polygon that contains all of `points`
+    ///
+    /// Duplicate points and points along the edges are removed, the result is clockwise (on screen)
+    /// and starts with the left most point
+    ///
+    /// `points` must not be empty
+    #[must_use]
+    pub fn convex_hull(points: &[Coord]) -> Self {
+        debug_assert!(!points.is_empty());
+        let mut points = points.to_vec();
+        points.sort_by_key(|p| (p.x, p.y));
+        points.dedup();
+        if points.len() < 3 {
+            return Polygon::new(&points);
+        }
+        let turn = |a: Coord, b: Coord, c: Coord| (b - a).cross_product(c - a);
+        let mut hull: Vec<Coord> = Vec::with_capacity(points.len() * 2);
+        for &point in &points {
+            while hull.len() >= 2 && turn(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0 {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+        let lower_len = hull.len() + 1;
+        for &point in points.iter().rev().skip(1) {
+            while hull.len() >= lower_len
+                && turn(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0
+            {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+        hull.pop();
+        Polygon::new(&hull)
+    }
 }
 
 impl Polygon {
@@ -46,7 +194,11 @@ impl Polygon {
         &self.fpoints
     }
 
-    /// Returns true if all sides are the same length
+    /// Returns true if the polygon is convex and all sides are the same length and all corners are
+    /// the same distance from the middle
+    ///
+    /// Small differences are allowed as corners are rounded to whole pixels, so polygons stay
+    /// regular after being moved, rotated or scaled
     #[inline]
     #[must_use]
     pub fn is_regular(&self) -> bool {
@@ -75,6 +227,191 @@ impl Polygon {
     pub fn is_convex(&self) -> bool {
         self.is_convex
     }
+
+    /// Remove corners that are at most `tolerance` away from the simplified outline,
+    /// see [simplify_rdp]
+    ///
+    /// Useful for reducing the number of points from [Ellipse::as_polygon] or traced outlines
+    #[must_use]
+    pub fn simplify(&self, tolerance: f32) -> Polygon {
+        Polygon::new(&simplify_rdp(&self.points, tolerance, true))
+    }
+
+    /// Remove corners that make a triangle smaller than `min_area` with their neighbours,
+    /// see [simplify_visvalingam]
+    #[must_use]
+    pub fn simplify_area(&self, min_area: f32) -> Polygon {
+        Polygon::new(&simplify_visvalingam(&self.points, min_area, true))
+    }
+
+    /// Direction the points go around the polygon
+    ///
+    /// Polygons without an area are treated as clockwise, polygons whose lines cross use
+    /// whichever direction covers the most area
+    #[must_use]
+    pub fn winding(&self) -> Winding {
+        if signed_area_x2(&self.points) < 0 {
+            Winding::CounterClockwise
+        } else {
+            Winding::Clockwise
+        }
+    }
+
+    /// Returns a copy of the polygon with the points in clockwise order, see [Polygon::winding]
+    ///
+    /// The first point stays the same
+    #[must_use]
+    pub fn to_clockwise(&self) -> Polygon {
+        match self.winding() {
+            Winding::Clockwise => self.clone(),
+            Winding::CounterClockwise => self.reversed(),
+        }
+    }
+
+    /// Returns a copy of the polygon with the points in counter clockwise order, see [Polygon::winding]
+    ///
+    /// The first point stays the same
+    #[must_use]
+    pub fn to_counter_clockwise(&self) -> Polygon {
+        match self.winding() {
+            Winding::Clockwise => self.reversed(),
+            Winding::CounterClockwise => self.clone(),
+        }
+    }
+
+    fn reversed(&self) -> Polygon {
+        let mut points = self.points.clone();
+        if points.len() > 1 {
+            points[1..].reverse();
+        }
+        self.rebuild(&points)
+    }
+
+    /// Area inside the polygon
+    ///
+    /// Only correct for polygons whose lines don't cross each other
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        signed_area_x2(&self.points).unsigned_abs() as f32 / 2.0
+    }
+
+    /// Grow (positive `distance`) or shrink (negative `distance`) the polygon so every side is moved
+    /// `distance` away from the original side
+    ///
+    /// Shrinking can split the polygon into several polygons or remove it entirely (returning an
+    /// empty list), growing a concave polygon may fill in any gaps narrower than `distance * 2`
+    ///
+    /// Only works for polygons whose lines don't cross each other
+    #[must_use]
+    pub fn offset(&self, distance: isize, join: Join) -> Vec<Polygon> {
+        if distance == 0 {
+            return vec![self.clone()];
+        }
+        let mut points: Vec<(f64, f64)> = self
+            .points
+            .iter()
+            .map(|p| (p.x as f64, p.y as f64))
+            .collect();
+        points.dedup();
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        if points.len() < 3 {
+            return vec![];
+        }
+        if self.winding() == Winding::CounterClockwise {
+            points.reverse();
+        }
+        let raw = offset_ring(&points, distance as f64, join);
+        let limit = distance.unsigned_abs() as f64 * 0.99 - 0.01;
+        // only corners from `offset_ring` are checked for distance, crossings between bevelled
+        // or rounded corners are legitimately closer to the original polygon
+        split_self_intersections(&raw)
+            .into_iter()
+            .filter(|ring| {
+                fsigned_area(ring) > 0.0
+                    && ring.iter().all(|point| {
+                        (!raw.contains(point) || distance_to_ring(*point, &points) >= limit)
+                            && (distance > 0 || fcontains(&points, *point))
+                    })
+            })
+            .filter_map(|ring| {
+                let mut corners: Vec<Coord> = ring
+                    .iter()
+                    .map(|(x, y)| coord!(x.round(), y.round()))
+                    .collect();
+                corners.dedup();
+                if corners.len() > 1 && corners.first() == corners.last() {
+                    corners.pop();
+                }
+                (corners.len() >= 3).then(|| Polygon::new(&corners))
+            })
+            .collect()
+    }
+
+    /// Check the polygon is simple (it has an area, none of its sides cross and no points are
+    /// duplicated or unnecessary)
+    ///
+    /// Some methods, such as [contains](Shape::contains), [area](Polygon::area) and
+    /// [offset](Polygon::offset), give unexpected results for invalid polygons, see [Polygon::repair]
+    pub fn validate(&self) -> Result<(), PolygonError> {
+        let points = &self.points;
+        let len = points.len();
+        if len < 3 {
+            return Err(PolygonError::TooFewPoints(len));
+        }
+        for i in 0..len {
+            if points[i] == points[(i + 1) % len] {
+                return Err(PolygonError::DuplicatePoint(i));
+            }
+        }
+        for i in 0..len {
+            let prev = points[(i + len - 1) % len];
+            let next = points[(i + 1) % len];
+            if (prev - points[i]).cross_product(next - points[i]) == 0 {
+                return Err(PolygonError::CollinearPoint(i));
+            }
+        }
+        if signed_area_x2(points) == 0 {
+            return Err(PolygonError::ZeroArea);
+        }
+        let lines = self.as_lines();
+        for i in 0..len {
+            for j in i + 2..len {
+                if i == 0 && j == len - 1 {
+                    continue;
+                }
+                if lines[i].intersects_line(&lines[j]) {
+                    return Err(PolygonError::SelfIntersection(i, j));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fix any problems reported by [Polygon::validate]
+    ///
+    /// Duplicate and collinear points are removed, the polygon is split where the sides cross and
+    /// each part is made clockwise. Parts without an area are dropped, so the result may be empty
+    #[must_use]
+    pub fn repair(&self) -> Vec<Polygon> {
+        let points: Vec<(f64, f64)> = self
+            .points
+            .iter()
+            .map(|p| (p.x as f64, p.y as f64))
+            .collect();
+        split_self_intersections(&points)
+            .into_iter()
+            .filter_map(|ring| {
+                let mut corners: Vec<Coord> = ring
+                    .iter()
+                    .map(|(x, y)| coord!(x.round(), y.round()))
+                    .collect();
+                remove_unnecessary_points(&mut corners);
+                (corners.len() >= 3).then(|| Polygon::new(&corners).to_clockwise())
+            })
+            .collect()
+    }
 }
 
 impl Shape for Polygon {
@@ -93,6 +430,9 @@ impl Shape for Polygon {
     }
 
     fn contains(&self, point: Coord) -> bool {
+        if self.fpoints.is_empty() {
+            return false;
+        }
         let mut j = self.fpoints.len() - 1;
         let mut odd_number_of_nodes = false;
         let fpoint = (point.x as f32, point.y as f32);
@@ -131,6 +471,9 @@ impl Shape for Polygon {
 
     fn filled_pixels(&self) -> Vec<Coord> {
         let mut output = vec![];
+        if self.points.is_empty() {
+            return output;
+        }
         let poly: Vec<(f32, f32)> = self
             .points
             .iter()
@@ -173,6 +516,23 @@ impl Shape for Polygon {
         output
     }
 
+    /// Unlike the default this keeps concave corners
+    ///
+    /// Polygons without any area can't be offset and are returned unchanged
+    fn buffer(&self, distance: usize) -> Polygon {
+        if self.area() == 0.0 {
+            return self.clone();
+        }
+        self.offset(distance as isize, Join::Round)
+            .into_iter()
+            .max_by(|a, b| a.area().total_cmp(&b.area()))
+            .expect("growing a polygon with an area always leaves a ring")
+    }
+
+    fn signed_distance(&self, point: Coord) -> f32 {
+        polygon_signed_distance(&self.points, point)
+    }
+
     fn to_shape_box(&self) -> ShapeBox {
         ShapeBox::Polygon(self.clone())
     }
@@ -217,13 +577,10 @@ impl Polygon {
 
     #[must_use]
     pub fn as_lines(&self) -> Vec<Line> {
-        let mut lines = vec![];
-        let poly = self.points.clone();
-        for i in 0..poly.len() - 1 {
-            lines.push(Line::new(poly[i], poly[i + 1]));
-        }
-        lines.push(Line::new(poly[poly.len() - 1], poly[0]));
-        lines
+        let poly = &self.points;
+        (0..poly.len())
+            .map(|i| Line::new(poly[i], poly[(i + 1) % poly.len()]))
+            .collect()
     }
 
     /// Cuts shape into triangles, triangles will be from the center to the edge
@@ -247,6 +604,285 @@ impl Polygon {
     }
 }
 
+/// Removes duplicate and collinear points (including spikes where the outline doubles back)
+fn remove_unnecessary_points(points: &mut Vec<Coord>) {
+    let mut changed = true;
+    while changed && !points.is_empty() {
+        changed = false;
+        points.dedup();
+        while points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        let len = points.len();
+        if len < 3 {
+            return;
+        }
+        for i in 0..len {
+            let prev = points[(i + len - 1) % len];
+            let next = points[(i + 1) % len];
+            if (prev - points[i]).cross_product(next - points[i]) == 0 {
+                points.remove(i);
+                changed = true;
+                break;
+            }
+        }
+    }
+}
+
+/// Moves every side of `points` (which must be clockwise) outwards by `distance`
+///
+/// The result will cross itself near concave corners (or convex corners when shrinking), these
+/// parts are removed by [Polygon::offset]
+fn offset_ring(points: &[(f64, f64)], distance: f64, join: Join) -> Vec<(f64, f64)> {
+    let len = points.len();
+    let normal = |from: (f64, f64), to: (f64, f64)| {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let length = (dx * dx + dy * dy).sqrt();
+        (dy / length, -dx / length)
+    };
+    let mut output = vec![];
+    for i in 0..len {
+        let prev = points[(i + len - 1) % len];
+        let point = points[i];
+        let next = points[(i + 1) % len];
+        let n1 = normal(prev, point);
+        let n2 = normal(point, next);
+        let a = (point.0 + n1.0 * distance, point.1 + n1.1 * distance);
+        let b = (point.0 + n2.0 * distance, point.1 + n2.1 * distance);
+        let turn =
+            (point.0 - prev.0) * (next.1 - point.1) - (point.1 - prev.1) * (next.0 - point.0);
+        if turn * distance <= 0.0 {
+            output.extend([a, b]);
+            continue;
+        }
+        match join {
+            Join::Miter => {
+                let dir = (point.0 - prev.0, point.1 - prev.1);
+                let next_dir = (next.0 - point.0, next.1 - point.1);
+                let denom = dir.0 * next_dir.1 - dir.1 * next_dir.0;
+                let t = ((b.0 - a.0) * next_dir.1 - (b.1 - a.1) * next_dir.0) / denom;
+                let miter = (a.0 + dir.0 * t, a.1 + dir.1 * t);
+                let miter_length =
+                    ((miter.0 - point.0).powi(2) + (miter.1 - point.1).powi(2)).sqrt();
+                if miter_length <= distance.abs() * MITER_LIMIT {
+                    output.push(miter);
+                } else {
+                    output.extend([a, b]);
+                }
+            }
+            Join::Round => {
+                output.push(a);
+                let start = (a.1 - point.1).atan2(a.0 - point.0);
+                let va = (a.0 - point.0, a.1 - point.1);
+                let vb = (b.0 - point.0, b.1 - point.1);
+                let sweep = (va.0 * vb.1 - va.1 * vb.0).atan2(va.0 * vb.0 + va.1 * vb.1);
+                let steps = (sweep.abs() / ROUND_STEP).ceil() as usize;
+                for step in 1..steps {
+                    let angle = start + sweep * step as f64 / steps as f64;
+                    output.push((
+                        point.0 + distance.abs() * angle.cos(),
+                        point.1 + distance.abs() * angle.sin(),
+                    ));
+                }
+                output.push(b);
+            }
+            Join::Bevel => output.extend([a, b]),
+        }
+    }
+    output
+}
+
+/// Miters longer than this multiple of the offset distance are bevelled instead
+const MITER_LIMIT: f64 = 4.0;
+/// Max angle between the points of a [Join::Round] corner
+const ROUND_STEP: f64 = std::f64::consts::PI / 12.0;
+
+/// Split `points` at every place its lines cross or touch into separate rings
+fn split_self_intersections(points: &[(f64, f64)]) -> Vec<Vec<(f64, f64)>> {
+    let mut points = points.to_vec();
+    points.dedup();
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    let len = points.len();
+    for i in 0..len {
+        for j in i + 2..len {
+            if i == 0 && j == len - 1 {
+                continue;
+            }
+            let crossing =
+                fsegment_intersection(points[i], points[i + 1], points[j], points[(j + 1) % len]);
+            if let Some(crossing) = crossing {
+                let mut first = vec![crossing];
+                first.extend_from_slice(&points[i + 1..=j]);
+                let mut second = points[..=i].to_vec();
+                second.push(crossing);
+                second.extend_from_slice(&points[j + 1..]);
+                let mut output = split_self_intersections(&first);
+                output.extend(split_self_intersections(&second));
+                return output;
+            }
+        }
+    }
+    vec![points]
+}
+
+/// Point where the lines `a1`-`a2` and `b1`-`b2` cross or touch, if they do
+///
+/// Parallel lines are never counted
+fn fsegment_intersection(
+    a1: (f64, f64),
+    a2: (f64, f64),
+    b1: (f64, f64),
+    b2: (f64, f64),
+) -> Option<(f64, f64)> {
+    let r = (a2.0 - a1.0, a2.1 - a1.1);
+    let s = (b2.0 - b1.0, b2.1 - b1.1);
+    let denom = r.0 * s.1 - r.1 * s.0;
+    if denom.abs() < f64::EPSILON {
+        return None;
+    }
+    let diff = (b1.0 - a1.0, b1.1 - a1.1);
+    let t = (diff.0 * s.1 - diff.1 * s.0) / denom;
+    let u = (diff.0 * r.1 - diff.1 * r.0) / denom;
+    const EDGE: f64 = 1e-9;
+    let range = -EDGE..=1.0 + EDGE;
+    if range.contains(&t) && range.contains(&u) {
+        Some((a1.0 + r.0 * t, a1.1 + r.1 * t))
+    } else {
+        None
+    }
+}
+
+/// Same as [signed_area_x2] but halved and for unrounded points
+fn fsigned_area(points: &[(f64, f64)]) -> f64 {
+    let mut total = 0.0;
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        total += a.0 * b.1 - a.1 * b.0;
+    }
+    total / 2.0
+}
+
+/// Distance from `point` to the closest side of `points`
+fn distance_to_ring(point: (f64, f64), points: &[(f64, f64)]) -> f64 {
+    (0..points.len())
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % points.len()]);
+            let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+            let len_sq = dx * dx + dy * dy;
+            let t = if len_sq == 0.0 {
+                0.0
+            } else {
+                (((point.0 - a.0) * dx + (point.1 - a.1) * dy) / len_sq).clamp(0.0, 1.0)
+            };
+            ((point.0 - a.0 - dx * t).powi(2) + (point.1 - a.1 - dy * t).powi(2)).sqrt()
+        })
+        .fold(f64::MAX, f64::min)
+}
+
+/// Even-odd test for unrounded points
+fn fcontains(points: &[(f64, f64)], point: (f64, f64)) -> bool {
+    let mut inside = false;
+    let mut j = points.len() - 1;
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[j]);
+        if (a.1 > point.1) != (b.1 > point.1)
+            && point.0 < (b.0 - a.0) * (point.1 - a.1) / (b.1 - a.1) + a.0
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Same as [Coord::from_angle] but without rounding the angle
+fn point_on_circle(center: Coord, radius: f64, degrees: f64) -> Coord {
+    let rads = (degrees - 90.0).to_radians();
+    coord!(
+        (center.x as f64 + radius * rads.cos()).round(),
+        (center.y as f64 + radius * rads.sin()).round()
+    )
+}
+
+/// Shoelace formula, doubled to avoid losing precision
+///
+/// Positive if the points are clockwise (on screen, where y increases downwards)
+fn signed_area_x2(points: &[Coord]) -> isize {
+    let mut total = 0;
+    for i in 0..points.len() {
+        total += points[i].cross_product(points[(i + 1) % points.len()]);
+    }
+    total
+}
+
+/// How far (in pixels) sides and corners can be from the average and still count as regular,
+/// this allows for both ends of a side being rounded to whole pixels
+const REGULAR_TOLERANCE: f32 = 1.5;
+/// As [REGULAR_TOLERANCE] but as a fraction of the average, for polygons that have been
+/// scaled up after being rounded
+const REGULAR_TOLERANCE_PERCENT: f32 = 0.02;
+
+/// True if every side is the same length, every corner is the same distance from the
+/// middle and every corner has the same angle
+///
+/// Sides and distances can differ by [REGULAR_TOLERANCE] or [REGULAR_TOLERANCE_PERCENT]
+/// (whichever is larger) and angles by as much as moving the ends of the shortest side that far
+/// allows. If every angle matches exactly then the corners can't have been rounded (such as
+/// with rectangles), so the sides must match exactly too
+fn is_regular(points: &[Coord]) -> bool {
+    if points.len() < 3 {
+        return false;
+    }
+    let len = points.len();
+    let count = len as f32;
+    let (sum_x, sum_y) = points.iter().fold((0.0, 0.0), |(x, y), point| {
+        (x + point.x as f32, y + point.y as f32)
+    });
+    let middle = (sum_x / count, sum_y / count);
+    let radii: Vec<f32> = points
+        .iter()
+        .map(|point| {
+            ((point.x as f32 - middle.0).powi(2) + (point.y as f32 - middle.1).powi(2)).sqrt()
+        })
+        .collect();
+    let sides: Vec<f32> = (0..len)
+        .map(|i| {
+            let diff = points[(i + 1) % len] - points[i];
+            ((diff.x * diff.x + diff.y * diff.y) as f32).sqrt()
+        })
+        .collect();
+    let angles: Vec<f32> = (0..len)
+        .map(|i| {
+            let before = points[i] - points[(i + len - 1) % len];
+            let after = points[(i + 1) % len] - points[i];
+            let dot = before.x * after.x + before.y * after.y;
+            (before.cross_product(after) as f32).atan2(dot as f32)
+        })
+        .collect();
+    let average = |values: &[f32]| values.iter().sum::<f32>() / count;
+    let spread = |values: &[f32]| {
+        let average = average(values);
+        values
+            .iter()
+            .map(|value| (value - average).abs())
+            .fold(0.0, f32::max)
+    };
+    let within = |values: &[f32], tolerance: f32| spread(values) <= tolerance;
+    let tolerance = |length: f32| REGULAR_TOLERANCE.max(length * REGULAR_TOLERANCE_PERCENT);
+    let shortest = sides.iter().copied().fold(f32::MAX, f32::min);
+    let angle_tolerance = 2.0 * (tolerance(shortest) / shortest).atan();
+    let side_tolerance = if spread(&angles) <= f32::EPSILON {
+        f32::EPSILON
+    } else {
+        tolerance(average(&sides))
+    };
+    within(&radii, tolerance(average(&radii)))
+        && within(&sides, side_tolerance)
+        && within(&angles, angle_tolerance)
+}
+
 fn is_convex(points: &[Coord]) -> bool {
     let mut prev = 0;
     for i in 0..points.len() {
@@ -262,3 +898,495 @@ fn is_convex(points: &[Coord]) -> bool {
     }
     true
 }
+
+#[cfg(test)]
+mod test {
+    use crate::general_math::{distance_to_segment, segments_cross};
+    use crate::polygon::signed_area_x2;
+    use crate::prelude::*;
+
+    #[test]
+    fn regular() {
+        for sides in 3..12 {
+            let polygon = Polygon::regular((100, 100), 50, sides, 0);
+            assert_eq!(polygon.points().len(), sides);
+            assert!(polygon.is_regular(), "{sides} sides");
+            assert!(polygon.is_convex(), "{sides} sides");
+            assert_eq!(polygon.points()[0], coord!(100, 50));
+            for point in polygon.points() {
+                assert!((49..=51).contains(&point.distance((100, 100))));
+            }
+        }
+    }
+
+    #[test]
+    fn regular_stays_regular() {
+        let polygon = Polygon::regular((100, 100), 50, 5, 10);
+        assert!(polygon.translate_by(coord!(10, 30)).is_regular());
+        assert!(polygon.rotate(45).is_regular());
+        assert!(polygon.scale(2.0).is_regular());
+    }
+
+    #[test]
+    fn is_regular_is_calculated() {
+        for sides in 3..12 {
+            for radius in [10, 50, 200] {
+                for rotation in [0, 17, 45] {
+                    let regular = Polygon::regular((100, 100), radius, sides, rotation);
+                    let copy = Polygon::new(&regular.points());
+                    assert!(copy.is_regular(), "{sides} sides {radius} {rotation}");
+                }
+            }
+        }
+        assert!(Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10)]).is_regular());
+        assert!(!Polygon::new(&[(0, 0), (20, 0), (20, 10), (0, 10)]).is_regular());
+        assert!(!Polygon::new(&[(0, 0), (10, 0), (10, 11), (0, 11)]).is_regular());
+        assert!(!Polygon::new(&[(0, 0), (20, 0), (20, 21), (0, 21)]).is_regular());
+        assert!(!Rect::new((0, 0), (20, 21)).as_polygon().is_regular());
+        assert!(!Polygon::new(&[(0, 0), (10, 0), (20, 10), (10, 10)]).is_regular());
+
+        let regular = Polygon::regular((50, 50), 40, 5, 0);
+        let rebuilt = regular.rebuild(&[
+            coord!(0, 0),
+            coord!(100, 0),
+            coord!(100, 3),
+            coord!(50, 90),
+            coord!(0, 7),
+        ]);
+        assert!(!rebuilt.is_regular());
+    }
+
+    #[test]
+    fn regular_rotation() {
+        let polygon = Polygon::regular((0, 0), 10, 4, 45);
+        assert_eq!(
+            polygon.points(),
+            coord_vec![(7, -7), (7, 7), (-7, 7), (-7, -7)]
+        );
+    }
+
+    /// Only works for clockwise convex polygons
+    fn inside_or_on_edge(polygon: &Polygon, point: Coord) -> bool {
+        polygon
+            .as_lines()
+            .iter()
+            .all(|line| (line.end() - line.start()).cross_product(point - line.start()) >= 0)
+    }
+
+    #[test]
+    fn convex_hull() {
+        let points = [
+            coord!(0, 0),
+            coord!(10, 0),
+            coord!(5, 0),
+            coord!(5, 5),
+            coord!(10, 10),
+            coord!(3, 7),
+            coord!(0, 10),
+            coord!(10, 10),
+            coord!(0, 5),
+        ];
+        let hull = Polygon::convex_hull(&points);
+        assert!(hull.is_convex());
+        assert_eq!(
+            hull.points(),
+            vec![coord!(0, 0), coord!(10, 0), coord!(10, 10), coord!(0, 10)]
+        );
+        for point in points {
+            assert!(inside_or_on_edge(&hull, point), "{point:?}");
+        }
+    }
+
+    #[test]
+    fn convex_hull_of_concave() {
+        let star = Polygon::star((50, 50), 40, 15, 5, 0);
+        assert!(!star.is_convex());
+        let hull = star.convex_hull();
+        assert!(hull.is_convex());
+        assert_eq!(hull.points().len(), 5);
+        for point in star.points() {
+            assert!(inside_or_on_edge(&hull, point), "{point:?}");
+        }
+    }
+
+    #[test]
+    fn convex_hull_degenerate() {
+        assert_eq!(
+            Polygon::convex_hull(&[coord!(5, 5), coord!(5, 5)]).points(),
+            vec![coord!(5, 5)]
+        );
+        let line = Polygon::convex_hull(&[coord!(0, 0), coord!(5, 5), coord!(10, 10)]);
+        assert_eq!(line.points(), vec![coord!(0, 0), coord!(10, 10)]);
+    }
+
+    fn max_deviation(original: &Polygon, simplified: &Polygon) -> f32 {
+        original
+            .points()
+            .iter()
+            .map(|point| {
+                simplified
+                    .as_lines()
+                    .iter()
+                    .map(|line| distance_to_segment(*point, line.start(), line.end()))
+                    .fold(f32::MAX, f32::min)
+            })
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn simplify() {
+        let ellipse = Ellipse::new((100, 100), 120, 60).as_polygon();
+        for tolerance in [0.5, 1.0, 3.0, 10.0] {
+            let simplified = ellipse.simplify(tolerance);
+            assert!(simplified.points().len() >= 3);
+            assert!(simplified.points().len() < ellipse.points().len());
+            assert!(simplified.is_convex());
+            assert!(
+                max_deviation(&ellipse, &simplified) <= tolerance,
+                "{tolerance}"
+            );
+        }
+        let square = Polygon::new(&[(0, 0), (5, 0), (10, 0), (10, 10), (0, 10), (0, 5)]);
+        assert_eq!(
+            square.simplify(0.5).points(),
+            vec![coord!(0, 0), coord!(10, 0), coord!(10, 10), coord!(0, 10)]
+        );
+        assert_eq!(square.simplify(100.0).points().len(), 3);
+    }
+
+    #[test]
+    fn simplify_keeps_topology() {
+        // a thin U shape, the gap is narrower than the tolerance
+        let u_shape = Polygon::new(&[
+            (0, 0),
+            (4, 0),
+            (4, 20),
+            (6, 20),
+            (6, 0),
+            (10, 0),
+            (10, 25),
+            (0, 25),
+        ]);
+        let simplified = u_shape.simplify(5.0);
+        let lines = simplified.as_lines();
+        for (i, a) in lines.iter().enumerate() {
+            for b in &lines[i + 1..] {
+                assert!(!segments_cross(a.start(), a.end(), b.start(), b.end()));
+            }
+        }
+    }
+
+    #[test]
+    fn simplify_area() {
+        let ellipse = Ellipse::new((100, 100), 120, 60).as_polygon();
+        let simplified = ellipse.simplify_area(10.0);
+        assert!(simplified.points().len() >= 3);
+        assert!(simplified.points().len() < ellipse.points().len());
+        assert!((ellipse.area() - simplified.area()).abs() < ellipse.area() * 0.05);
+        let triangle = Polygon::new(&[(0, 0), (10, 0), (0, 10)]);
+        assert_eq!(triangle.simplify_area(1000.0), triangle);
+    }
+
+    #[test]
+    fn offset_square() {
+        let square = Polygon::new(&[(0, 0), (20, 0), (20, 20), (0, 20)]);
+        let grown = square.offset(5, Join::Miter);
+        assert_eq!(grown.len(), 1);
+        assert_eq!(
+            grown[0].points(),
+            vec![
+                coord!(-5, -5),
+                coord!(25, -5),
+                coord!(25, 25),
+                coord!(-5, 25)
+            ]
+        );
+        let bevel = square.offset(5, Join::Bevel);
+        assert_eq!(bevel[0].points().len(), 8);
+        let round = square.offset(5, Join::Round);
+        assert!(round[0].points().len() > 8);
+        assert!(!round[0].contains(coord!(-4, -4)));
+        assert_eq!(round[0].left(), -5);
+        assert_eq!(round[0].bottom(), 25);
+
+        let shrunk = square.offset(-5, Join::Round);
+        assert_eq!(shrunk.len(), 1);
+        assert_eq!(
+            shrunk[0].points(),
+            vec![coord!(5, 5), coord!(15, 5), coord!(15, 15), coord!(5, 15)]
+        );
+        assert!(square.offset(-10, Join::Miter).is_empty());
+        assert!(square.offset(-15, Join::Miter).is_empty());
+    }
+
+    #[test]
+    fn offset_keeps_distance() {
+        let l_shape = Polygon::new(&[(0, 0), (10, 0), (10, 30), (30, 30), (30, 40), (0, 40)]);
+        for distance in [-3, 2, 6] {
+            let offset = l_shape.offset(distance, Join::Round);
+            assert_eq!(offset.len(), 1, "{distance}");
+            for point in offset[0].points() {
+                let dist = l_shape
+                    .as_lines()
+                    .iter()
+                    .map(|line| distance_to_segment(point, line.start(), line.end()))
+                    .fold(f32::MAX, f32::min);
+                assert!(
+                    (dist - distance.abs() as f32).abs() <= 1.0,
+                    "{point:?} {dist}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn offset_splits() {
+        // two squares joined by a thin bridge
+        let dumbbell = Polygon::new(&[
+            (0, 0),
+            (20, 0),
+            (20, 8),
+            (40, 8),
+            (40, 0),
+            (60, 0),
+            (60, 20),
+            (40, 20),
+            (40, 12),
+            (20, 12),
+            (20, 20),
+            (0, 20),
+        ]);
+        let shrunk = dumbbell.offset(-3, Join::Miter);
+        assert_eq!(shrunk.len(), 2);
+        assert!(shrunk
+            .iter()
+            .any(|polygon| polygon.contains(coord!(10, 10))));
+        assert!(shrunk
+            .iter()
+            .any(|polygon| polygon.contains(coord!(50, 10))));
+        let grown = dumbbell.offset(3, Join::Miter);
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown[0].left(), -3);
+        assert_eq!(grown[0].right(), 63);
+    }
+
+    #[test]
+    fn offset_counter_clockwise() {
+        let square = Polygon::new(&[(0, 20), (20, 20), (20, 0), (0, 0)]);
+        let grown = square.offset(5, Join::Miter);
+        assert_eq!(grown[0].left(), -5);
+        assert_eq!(grown[0].right(), 25);
+    }
+
+    #[test]
+    fn offset_narrow_notch() {
+        let notched = Polygon::new(&[
+            (0, 0),
+            (30, 0),
+            (30, 30),
+            (16, 30),
+            (16, 10),
+            (14, 10),
+            (14, 30),
+            (0, 30),
+        ]);
+        for join in [Join::Miter, Join::Bevel, Join::Round] {
+            let grown = notched.offset(3, join);
+            assert_eq!(grown.len(), 1, "{join:?}");
+            assert!(grown[0].area() > 1250.0, "{join:?} {}", grown[0].area());
+            assert!(grown[0].contains(coord!(15, 20)), "{join:?}");
+            let shrunk = notched.offset(-3, join);
+            assert_eq!(shrunk.len(), 1, "{join:?}");
+            assert!(shrunk[0].contains(coord!(5, 20)), "{join:?}");
+            assert!(shrunk[0].contains(coord!(25, 20)), "{join:?}");
+            assert!(!shrunk[0].contains(coord!(15, 20)), "{join:?}");
+        }
+        assert!(notched.buffer(3).contains(coord!(15, 20)));
+    }
+
+    #[test]
+    fn offset_star() {
+        let star = Polygon::star((100, 100), 50, 20, 5, 0);
+        for join in [Join::Miter, Join::Bevel, Join::Round] {
+            let grown = star.offset(4, join);
+            assert_eq!(grown.len(), 1, "{join:?}");
+            assert!(grown[0].area() > star.area(), "{join:?}");
+            for point in star.points() {
+                assert!(grown[0].contains(point), "{join:?} {point:?}");
+            }
+            let shrunk = star.offset(-4, join);
+            assert_eq!(shrunk.len(), 1, "{join:?}");
+            assert!(shrunk[0].area() < star.area(), "{join:?}");
+            assert!(shrunk[0].contains(coord!(100, 100)), "{join:?}");
+        }
+    }
+
+    #[test]
+    fn buffer() {
+        let rect = Rect::new((0, 0), (20, 10)).buffer(5);
+        assert_eq!(rect.left(), -5);
+        assert_eq!(rect.top(), -5);
+        assert_eq!(rect.right(), 25);
+        assert_eq!(rect.bottom(), 15);
+        assert!(!rect.contains(coord!(-4, -4)));
+
+        let triangle = Triangle::new((0, 0), (20, 0), (0, 20)).buffer(2);
+        assert!(triangle.contains(coord!(-1, 10)));
+        assert!(triangle.contains(coord!(11, 10)));
+
+        let circle = Circle::new((0, 0), 10).buffer(5);
+        assert!(circle.contains(coord!(14, 0)));
+        assert!(!circle.contains(coord!(16, 0)));
+
+        let ellipse = Ellipse::new((0, 0), 20, 10).buffer(5);
+        assert!(ellipse.contains(coord!(14, 0)));
+        assert!(ellipse.contains(coord!(0, 9)));
+        assert!(!ellipse.contains(coord!(0, 11)));
+
+        let line = Line::new((0, 0), (20, 0)).buffer(4);
+        assert!(line.contains(coord!(10, 3)));
+        assert!(line.contains(coord!(-3, 0)));
+        assert!(!line.contains(coord!(10, 5)));
+        assert_eq!(line, Capsule::new((0, 0), (20, 0), 4).as_polygon());
+    }
+
+    #[test]
+    fn empty() {
+        let polygon = Polygon::new::<Coord>(&[]);
+        assert!(polygon.as_lines().is_empty());
+        assert!(!polygon.contains(coord!(0, 0)));
+        assert!(polygon.filled_pixels().is_empty());
+        assert!(polygon.outline_pixels().is_empty());
+        assert!(!polygon.is_regular());
+        assert_eq!(polygon.validate(), Err(PolygonError::TooFewPoints(0)));
+        assert!(polygon.repair().is_empty());
+    }
+
+    #[test]
+    fn validate() {
+        let square = Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10)]);
+        assert_eq!(square.validate(), Ok(()));
+        assert_eq!(
+            Polygon::new(&[(0, 0), (10, 0)]).validate(),
+            Err(PolygonError::TooFewPoints(2))
+        );
+        assert_eq!(
+            Polygon::new(&[(0, 0), (10, 0), (10, 0), (10, 10)]).validate(),
+            Err(PolygonError::DuplicatePoint(1))
+        );
+        assert_eq!(
+            Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10), (0, 0)]).validate(),
+            Err(PolygonError::DuplicatePoint(4))
+        );
+        assert_eq!(
+            Polygon::new(&[(0, 0), (5, 0), (10, 0), (10, 10), (0, 10)]).validate(),
+            Err(PolygonError::CollinearPoint(1))
+        );
+        assert_eq!(
+            Polygon::new(&[(0, 0), (10, 10), (10, 0), (0, 10)]).validate(),
+            Err(PolygonError::ZeroArea)
+        );
+        assert_eq!(
+            Polygon::new(&[(0, 0), (10, 10), (10, 0), (0, 20)]).validate(),
+            Err(PolygonError::SelfIntersection(0, 2))
+        );
+        assert_eq!(
+            Polygon::new(&[(0, 0), (10, 0), (10, 10), (5, 0), (0, 10)]).validate(),
+            Err(PolygonError::SelfIntersection(0, 2))
+        );
+    }
+
+    #[test]
+    fn repair() {
+        let messy = Polygon::new(&[
+            (0, 0),
+            (0, 10),
+            (10, 10),
+            (10, 10),
+            (10, 5),
+            (10, 0),
+            (5, 0),
+        ]);
+        let repaired = messy.repair();
+        assert_eq!(repaired.len(), 1);
+        assert_eq!(repaired[0].validate(), Ok(()));
+        assert_eq!(repaired[0].points().len(), 4);
+        assert_eq!(repaired[0].area(), 100.0);
+        assert!(signed_area_x2(&repaired[0].points()) > 0);
+
+        let bowtie = Polygon::new(&[(0, 0), (10, 10), (10, 0), (0, 10)]);
+        let repaired = bowtie.repair();
+        assert_eq!(repaired.len(), 2);
+        for polygon in &repaired {
+            assert_eq!(polygon.validate(), Ok(()));
+            assert_eq!(polygon.area(), 25.0);
+            assert!(signed_area_x2(&polygon.points()) > 0);
+        }
+
+        let flat = Polygon::new(&[(0, 0), (5, 0), (10, 0)]);
+        assert_eq!(flat.validate(), Err(PolygonError::CollinearPoint(0)));
+        assert!(flat.repair().is_empty());
+    }
+
+    #[test]
+    fn winding() {
+        let clockwise = Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10)]);
+        let counter = Polygon::new(&[(0, 0), (0, 10), (10, 10), (10, 0)]);
+        assert_eq!(clockwise.winding(), Winding::Clockwise);
+        assert_eq!(counter.winding(), Winding::CounterClockwise);
+        assert_eq!(clockwise.to_counter_clockwise(), counter);
+        assert_eq!(counter.to_clockwise(), clockwise);
+        assert_eq!(clockwise.to_clockwise(), clockwise);
+        assert_eq!(counter.to_counter_clockwise(), counter);
+        let regular = Polygon::regular((50, 50), 20, 6, 0);
+        assert!(regular.to_counter_clockwise().is_regular());
+    }
+
+    #[test]
+    fn conversions_are_clockwise() {
+        let polygons = [
+            Rect::new((0, 0), (10, 20)).as_polygon(),
+            Rect::new((10, 20), (0, 0)).as_polygon(),
+            Rect::new((10, 0), (0, 20)).as_polygon(),
+            Ellipse::new((50, 50), 40, 20).as_polygon(),
+            Ellipse::new((50, 50), 40, 20).rotate(30).as_polygon(),
+            Circle::new((50, 50), 20).as_polygon(),
+            Capsule::new((0, 0), (30, 10), 5).as_polygon(),
+            Polygon::regular((50, 50), 20, 5, 0),
+            Polygon::star((50, 50), 20, 10, 5, 0),
+            Polygon::convex_hull(&[coord!(0, 0), coord!(0, 10), coord!(10, 10), coord!(10, 0)]),
+        ];
+        for polygon in polygons {
+            assert_eq!(polygon.winding(), Winding::Clockwise, "{polygon:?}");
+        }
+    }
+
+    #[test]
+    fn area() {
+        let square = Polygon::new(&[(0, 0), (10, 0), (10, 10), (0, 10)]);
+        assert_eq!(square.area(), 100.0);
+        let reversed = Polygon::new(&[(0, 10), (10, 10), (10, 0), (0, 0)]);
+        assert_eq!(reversed.area(), 100.0);
+        let triangle = Polygon::new(&[(0, 0), (10, 0), (0, 5)]);
+        assert_eq!(triangle.area(), 25.0);
+    }
+
+    #[test]
+    fn star() {
+        let star = Polygon::star((100, 100), 50, 20, 5, 0);
+        assert_eq!(star.points().len(), 10);
+        assert!(!star.is_regular());
+        assert!(!star.is_convex());
+        assert_eq!(star.points()[0], coord!(100, 50));
+        for (i, point) in star.points().iter().enumerate() {
+            let expected = if i % 2 == 0 { 49..=51 } else { 19..=21 };
+            assert!(expected.contains(&point.distance((100, 100))));
+        }
+    }
+
+    #[test]
+    fn star_with_equal_radii_is_regular_shape() {
+        let star = Polygon::star((100, 100), 40, 40, 4, 0);
+        assert!(star.is_convex());
+    }
+}
@@ -0,0 +1,299 @@
+use crate::general_math::distance_to_segment;
+use crate::new_hash_set;
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Polygon with cut outs
+///
+/// Made of an outer ring and any number of holes, the holes should be inside the outer ring
+/// and not overlap each other
+///
+/// The edges of holes are part of the shape, so pixels within half a pixel of the side of a hole
+/// are contained and included in [Shape::filled_pixels]
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+/// let frame = PolygonWithHoles::from((Rect::new((0, 0), (100, 100)), Rect::new((10, 10), (90, 90))));
+/// assert!(frame.contains(coord!(5, 50)));
+/// assert!(!frame.contains(coord!(50, 50)));
+/// assert_eq!(frame.area(), 3600.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonWithHoles {
+    outer: Polygon,
+    holes: Vec<Polygon>,
+}
+
+impl IntersectsContains for PolygonWithHoles {}
+
+impl PolygonWithHoles {
+    #[must_use]
+    pub fn new(outer: Polygon, holes: &[Polygon]) -> Self {
+        Self {
+            outer,
+            holes: holes.to_vec(),
+        }
+    }
+}
+
+impl PolygonWithHoles {
+    /// Outer ring
+    #[inline]
+    #[must_use]
+    pub fn outer(&self) -> &Polygon {
+        &self.outer
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn holes(&self) -> &[Polygon] {
+        &self.holes
+    }
+
+    /// Area of the outer ring minus the area of the holes
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        self.outer.area() - self.holes.iter().map(|hole| hole.area()).sum::<f32>()
+    }
+
+    /// Lines of the outer ring followed by the lines of each hole
+    #[must_use]
+    pub fn as_lines(&self) -> Vec<Line> {
+        let mut lines = self.outer.as_lines();
+        for hole in &self.holes {
+            lines.extend(hole.as_lines());
+        }
+        lines
+    }
+}
+
+impl Shape for PolygonWithHoles {
+    /// Creates a polygon without any holes
+    fn from_points(points: &[Coord]) -> Self
+    where
+        Self: Sized,
+    {
+        PolygonWithHoles::new(Polygon::from_points(points), &[])
+    }
+
+    /// `points` must be the same length and in the same order as [PolygonWithHoles::points]
+    fn rebuild(&self, points: &[Coord]) -> Self
+    where
+        Self: Sized,
+    {
+        let mut start = self.outer.points().len();
+        let outer = self.outer.rebuild(&points[..start]);
+        let holes: Vec<Polygon> = self
+            .holes
+            .iter()
+            .map(|hole| {
+                let end = start + hole.points().len();
+                let hole = hole.rebuild(&points[start..end]);
+                start = end;
+                hole
+            })
+            .collect();
+        PolygonWithHoles::new(outer, &holes)
+    }
+
+    fn contains(&self, point: Coord) -> bool {
+        self.outer.contains(point)
+            && !self
+                .holes
+                .iter()
+                .any(|hole| hole.contains(point) && !on_edge(hole, point))
+    }
+
+    /// Points of the outer ring followed by the points of each hole
+    fn points(&self) -> Vec<Coord> {
+        let mut points = self.outer.points();
+        for hole in &self.holes {
+            points.extend(hole.points());
+        }
+        points
+    }
+
+    #[inline]
+    fn center(&self) -> Coord {
+        self.outer.center()
+    }
+
+    fn left(&self) -> isize {
+        self.outer.left()
+    }
+
+    fn right(&self) -> isize {
+        self.outer.right()
+    }
+
+    fn top(&self) -> isize {
+        self.outer.top()
+    }
+
+    fn bottom(&self) -> isize {
+        self.outer.bottom()
+    }
+
+    fn outline_pixels(&self) -> Vec<Coord> {
+        let mut output = new_hash_set();
+        output.extend(self.outer.outline_pixels());
+        for hole in &self.holes {
+            output.extend(hole.outline_pixels());
+        }
+        output.into_iter().collect()
+    }
+
+    fn filled_pixels(&self) -> Vec<Coord> {
+        let mut cut_out = new_hash_set();
+        for hole in &self.holes {
+            cut_out.extend(
+                hole.filled_pixels()
+                    .into_iter()
+                    .filter(|point| !on_edge(hole, *point)),
+            );
+        }
+        let mut output = new_hash_set();
+        output.extend(
+            self.outer
+                .filled_pixels()
+                .into_iter()
+                .filter(|point| !cut_out.contains(point)),
+        );
+        output.into_iter().collect()
+    }
+
+    fn convex_hull(&self) -> Polygon {
+        self.outer.convex_hull()
+    }
+
+    /// Buffer of the outer ring, holes are ignored
+    fn buffer(&self, distance: usize) -> Polygon {
+        self.outer.buffer(distance)
+    }
+
+    /// Exact outside of the outer ring, may be slightly too small near corners of holes
+    fn signed_distance(&self, point: Coord) -> f32 {
+        self.holes
+            .iter()
+            .map(|hole| -hole.signed_distance(point))
+            .fold(self.outer.signed_distance(point), f32::max)
+    }
+
+    fn to_shape_box(&self) -> ShapeBox {
+        ShapeBox::PolygonWithHoles(self.clone())
+    }
+}
+
+/// Returns true if `point` is within half a pixel of any side of `hole`
+fn on_edge(hole: &Polygon, point: Coord) -> bool {
+    hole.as_lines()
+        .iter()
+        .any(|line| distance_to_segment(point, line.start(), line.end()) <= 0.5)
+}
+
+macro_rules! polygon_with_holes_pair {
+    ($outer: ty, $hole: ty) => {
+        impl From<($outer, $hole)> for PolygonWithHoles {
+            fn from(value: ($outer, $hole)) -> Self {
+                PolygonWithHoles::new(value.0.as_polygon(), &[value.1.as_polygon()])
+            }
+        }
+    };
+}
+
+polygon_with_holes_pair!(Rect, Rect);
+polygon_with_holes_pair!(Rect, Circle);
+polygon_with_holes_pair!(Circle, Rect);
+polygon_with_holes_pair!(Circle, Circle);
+
+impl From<Polygon> for PolygonWithHoles {
+    fn from(value: Polygon) -> Self {
+        PolygonWithHoles::new(value, &[])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn frame() -> PolygonWithHoles {
+        PolygonWithHoles::from((Rect::new((0, 0), (40, 40)), Rect::new((10, 10), (30, 30))))
+    }
+
+    #[test]
+    fn contains() {
+        let frame = frame();
+        assert!(frame.contains(coord!(5, 5)));
+        assert!(frame.contains(coord!(35, 20)));
+        assert!(!frame.contains(coord!(20, 20)));
+        assert!(!frame.contains(coord!(50, 20)));
+        assert!(frame.contains(coord!(10, 20)));
+        assert!(frame.contains(coord!(30, 20)));
+        assert!(frame.contains(coord!(20, 30)));
+    }
+
+    #[test]
+    fn filled_pixels_are_contained() {
+        let ring = PolygonWithHoles::from((Circle::new((50, 50), 40), Circle::new((50, 50), 20)));
+        for shape in [frame(), ring] {
+            for point in shape.filled_pixels() {
+                assert!(shape.contains(point), "{point:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn area() {
+        assert_eq!(frame().area(), 1200.0);
+        let ring = PolygonWithHoles::from((Circle::new((50, 50), 40), Circle::new((50, 50), 20)));
+        assert!(ring.area() < ring.outer().area());
+    }
+
+    #[test]
+    fn pixels() {
+        let frame = frame();
+        let filled = frame.filled_pixels();
+        assert!(filled.contains(&coord!(5, 5)));
+        assert!(filled.contains(&coord!(10, 20)));
+        assert!(!filled.contains(&coord!(20, 20)));
+        let outline = frame.outline_pixels();
+        assert!(outline.contains(&coord!(0, 20)));
+        assert!(outline.contains(&coord!(10, 20)));
+        assert!(!outline.contains(&coord!(5, 20)));
+    }
+
+    #[test]
+    fn transform() {
+        let frame = frame();
+        let moved = frame.translate_by(coord!(10, 5));
+        assert_eq!(moved.holes().len(), 1);
+        assert_eq!(moved.outer().top_left(), coord!(10, 5));
+        assert_eq!(moved.holes()[0].top_left(), coord!(20, 15));
+        assert!(!moved.contains(coord!(30, 25)));
+    }
+
+    #[test]
+    fn contains_shapes() {
+        let frame = frame();
+        assert!(frame.contains_rect(&Rect::new((2, 2), (8, 8))));
+        assert!(!frame.contains_rect(&Rect::new((15, 15), (25, 25))));
+        assert!(!frame.contains_rect(&Rect::new((5, 5), (35, 35))));
+        assert!(!frame.contains_rect(&Rect::new((5, 5), (15, 15))));
+        assert!(frame.contains_circle(&Circle::new((5, 20), 3)));
+        assert!(!frame.contains_circle(&Circle::new((20, 20), 3)));
+    }
+
+    #[test]
+    fn contains_shape_around_hole() {
+        let frame =
+            PolygonWithHoles::from((Rect::new((0, 0), (100, 100)), Rect::new((45, 45), (55, 55))));
+        assert!(frame.contains_annulus(&Annulus::new((50, 50), 10, 20)));
+        assert!(!frame.contains_annulus(&Annulus::new((50, 50), 5, 20)));
+        assert!(!frame.contains_circle(&Circle::new((50, 50), 20)));
+        assert!(!frame.contains_circle(&Circle::new((50, 50), 2)));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::general_math::{simplify_rdp, simplify_visvalingam};
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Series of connected lines
+///
+/// Unlike [Polygon] the last point is not connected to the first, a polyline is only closed if
+/// the first and last points are the same
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Polyline {
+    points: Vec<Coord>,
+}
+
+impl Polyline {
+    #[must_use]
+    pub fn new<'a, P: Into<Coord>>(points: &'a [P]) -> Self
+    where
+        Coord: From<&'a P>,
+    {
+        Self {
+            points: points.iter().map(|p| p.into()).collect(),
+        }
+    }
+}
+
+impl Polyline {
+    #[inline]
+    #[must_use]
+    pub fn points(&self) -> &[Coord] {
+        &self.points
+    }
+
+    /// Returns true if the first and last points are the same
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.points.len() > 1 && self.points.first() == self.points.last()
+    }
+
+    #[must_use]
+    pub fn as_lines(&self) -> Vec<Line> {
+        self.points
+            .windows(2)
+            .map(|points| Line::new(points[0], points[1]))
+            .collect()
+    }
+
+    /// Total length of all the lines
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.points
+            .windows(2)
+            .map(|points| {
+                let diff = points[1] - points[0];
+                ((diff.x * diff.x + diff.y * diff.y) as f32).sqrt()
+            })
+            .sum()
+    }
+
+    /// Remove points that are at most `tolerance` away from the simplified lines, see [simplify_rdp]
+    ///
+    /// The first and last points are always kept, so closed polylines stay closed
+    #[must_use]
+    pub fn simplify(&self, tolerance: f32) -> Polyline {
+        Polyline {
+            points: simplify_rdp(&self.points, tolerance, false),
+        }
+    }
+
+    /// Remove points that make a triangle smaller than `min_area` with their neighbours,
+    /// see [simplify_visvalingam]
+    ///
+    /// The first and last points are always kept, so closed polylines stay closed
+    #[must_use]
+    pub fn simplify_area(&self, min_area: f32) -> Polyline {
+        Polyline {
+            points: simplify_visvalingam(&self.points, min_area, false),
+        }
+    }
+}
+
+/// Creates a closed polyline
+impl From<Polygon> for Polyline {
+    fn from(value: Polygon) -> Self {
+        let mut points = value.points();
+        if let Some(first) = points.first().copied() {
+            points.push(first);
+        }
+        Polyline { points }
+    }
+}
+
+impl From<Line> for Polyline {
+    fn from(value: Line) -> Self {
+        Polyline {
+            points: vec![value.start(), value.end()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::general_math::distance_to_segment;
+    use crate::prelude::*;
+
+    fn max_deviation(original: &Polyline, simplified: &Polyline) -> f32 {
+        original
+            .points()
+            .iter()
+            .map(|point| {
+                simplified
+                    .points()
+                    .windows(2)
+                    .map(|line| distance_to_segment(*point, line[0], line[1]))
+                    .fold(f32::MAX, f32::min)
+            })
+            .fold(0.0, f32::max)
+    }
+
+    fn wave() -> Polyline {
+        let points: Vec<Coord> = (0..=100)
+            .map(|x| coord!(x as f64, ((x as f64 / 10.0).sin() * 20.0).round()))
+            .collect();
+        Polyline::new(&points)
+    }
+
+    #[test]
+    fn simplify_straight() {
+        let line = Polyline::new(&[(0, 0), (5, 0), (10, 0), (15, 1), (20, 0)]);
+        assert_eq!(line.simplify(1.0), Polyline::new(&[(0, 0), (20, 0)]));
+        assert_eq!(line.simplify(0.7).points().len(), 3);
+    }
+
+    #[test]
+    fn simplify_max_deviation() {
+        let wave = wave();
+        for tolerance in [0.5, 1.0, 2.0, 5.0] {
+            let simplified = wave.simplify(tolerance);
+            assert!(simplified.points().len() < wave.points().len());
+            assert_eq!(simplified.points()[0], wave.points()[0]);
+            assert_eq!(simplified.points().last(), wave.points().last());
+            assert!(
+                max_deviation(&wave, &simplified) <= tolerance,
+                "{tolerance}"
+            );
+        }
+    }
+
+    #[test]
+    fn simplify_area() {
+        let wave = wave();
+        let simplified = wave.simplify_area(5.0);
+        assert!(simplified.points().len() < wave.points().len());
+        assert_eq!(simplified.points()[0], wave.points()[0]);
+        assert_eq!(simplified.points().last(), wave.points().last());
+        assert!(max_deviation(&wave, &simplified) <= 5.0);
+    }
+
+    #[test]
+    fn stays_closed() {
+        let closed = Polyline::from(Ellipse::new((50, 50), 80, 40).as_polygon());
+        assert!(closed.is_closed());
+        assert!(closed.simplify(2.0).is_closed());
+        assert!(closed.simplify_area(20.0).is_closed());
+    }
+
+    #[test]
+    fn length() {
+        let line = Polyline::new(&[(0, 0), (3, 4), (3, 10)]);
+        assert_eq!(line.length(), 11.0);
+        assert_eq!(line.as_lines().len(), 2);
+        assert!(!line.is_closed());
+    }
+}
@@ -0,0 +1,373 @@
+use crate::distance::{
+    add, cross, dot, fcoord, length, normalise, parts, round, scale, sub, Part, Point,
+};
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Half line from `origin` in `direction`, optionally limited to `max_distance`
+///
+/// Used for line of sight, projectiles, mouse picking, etc, see [Shape::raycast]
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+/// let ray = Ray::new((0, 5), (1.0, 0.0));
+/// let hit = Rect::new((10, 0), (20, 10)).raycast(&ray).unwrap();
+/// assert_eq!(hit.point(), coord!(10, 5));
+/// assert_eq!(hit.distance(), 10.0);
+/// assert_eq!(hit.normal(), (-1.0, 0.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    origin: Coord,
+    direction: (f32, f32),
+    max_distance: Option<f32>,
+}
+
+impl Ray {
+    /// `direction` doesn't need to be normalised, rays with a direction of (0, 0) never hit anything
+    #[must_use]
+    pub fn new<P: Into<Coord>>(origin: P, direction: (f32, f32)) -> Self {
+        Self {
+            origin: origin.into(),
+            direction: normalise(direction),
+            max_distance: None,
+        }
+    }
+
+    /// Ray pointing at `degrees` (0 is up, same as [Coord::from_angle])
+    #[must_use]
+    pub fn from_angle<P: Into<Coord>>(origin: P, degrees: isize) -> Self {
+        let rads = (degrees as f32 - 90.0).to_radians();
+        Ray::new(origin, (rads.cos(), rads.sin()))
+    }
+
+    /// Ray from `origin` that stops at `target`, useful for line of sight checks
+    #[must_use]
+    pub fn between<P1: Into<Coord>, P2: Into<Coord>>(origin: P1, target: P2) -> Self {
+        let origin = origin.into();
+        let diff = target.into() - origin;
+        let direction = (diff.x as f32, diff.y as f32);
+        Ray::new(origin, direction).with_max_distance(length(direction))
+    }
+
+    #[must_use]
+    pub fn with_max_distance(self, max_distance: f32) -> Self {
+        Self {
+            max_distance: Some(max_distance),
+            ..self
+        }
+    }
+}
+
+impl Ray {
+    #[inline]
+    #[must_use]
+    pub fn origin(&self) -> Coord {
+        self.origin
+    }
+
+    /// Normalised direction
+    #[inline]
+    #[must_use]
+    pub fn direction(&self) -> (f32, f32) {
+        self.direction
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn max_distance(&self) -> Option<f32> {
+        self.max_distance
+    }
+
+    /// Point `distance` along the ray
+    #[must_use]
+    pub fn point_at(&self, distance: f32) -> Coord {
+        round(add(fcoord(self.origin), scale(self.direction, distance)))
+    }
+}
+
+/// Where a [Ray] hit a shape, see [Shape::raycast]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    point: Coord,
+    distance: f32,
+    normal: (f32, f32),
+}
+
+impl RayHit {
+    /// Point on the outline of the shape (rounded)
+    #[inline]
+    #[must_use]
+    pub fn point(&self) -> Coord {
+        self.point
+    }
+
+    /// Distance from the ray origin
+    #[inline]
+    #[must_use]
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// Direction (length of 1) of the outline surface at the hit, facing back towards the ray origin
+    #[inline]
+    #[must_use]
+    pub fn normal(&self) -> (f32, f32) {
+        self.normal
+    }
+}
+
+/// Cast `ray` against every shape in `shapes`, returns the index of the closest shape hit and the hit
+#[must_use]
+pub fn raycast_nearest(ray: &Ray, shapes: &[ShapeBox]) -> Option<(usize, RayHit)> {
+    shapes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, shape)| shape.raycast(ray).map(|hit| (i, hit)))
+        .min_by(|a, b| a.1.distance.total_cmp(&b.1.distance))
+}
+
+/// First point where `ray` crosses the outline of `shape`
+///
+/// Rays that start inside a shape hit the outline on the way out
+pub(crate) fn raycast(shape: &ShapeBox, ray: &Ray) -> Option<RayHit> {
+    if ray.direction == (0.0, 0.0) {
+        return None;
+    }
+    let hit = match shape {
+        ShapeBox::Ellipse(ellipse) => cast_ellipse(ellipse, ray),
+        ShapeBox::Group(group) => {
+            return raycast_nearest(ray, group.shapes()).map(|(_, hit)| hit);
+        }
+        shape => parts(shape)
+            .into_iter()
+            .filter_map(|part| match part {
+                Part::Segment(start, end) => cast_segment(ray, start, end),
+                Part::Circle(center, radius) => cast_circle(ray, center, radius),
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0)),
+    };
+    let (distance, normal) = hit?;
+    if ray.max_distance.map(|max| distance > max).unwrap_or(false) {
+        return None;
+    }
+    let normal = if dot(normal, ray.direction) > 0.0 {
+        scale(normal, -1.0)
+    } else {
+        normal
+    };
+    Some(RayHit {
+        point: ray.point_at(distance),
+        distance,
+        normal,
+    })
+}
+
+/// Distance along the ray and the normal of the line
+pub(crate) fn cast_segment(ray: &Ray, start: Point, end: Point) -> Option<(f32, Point)> {
+    let origin = fcoord(ray.origin);
+    let side = sub(end, start);
+    let normal = normalise((side.1, -side.0));
+    let to_start = sub(start, origin);
+    let denom = cross(ray.direction, side);
+    if denom == 0.0 {
+        if cross(to_start, ray.direction) != 0.0 {
+            return None;
+        }
+        // ray runs along the line
+        let near = dot(to_start, ray.direction);
+        let far = dot(sub(end, origin), ray.direction);
+        let (near, far) = (near.min(far), near.max(far));
+        return if far < 0.0 {
+            None
+        } else {
+            Some((near.max(0.0), scale(ray.direction, -1.0)))
+        };
+    }
+    let distance = cross(to_start, side) / denom;
+    let along = cross(to_start, ray.direction) / denom;
+    (distance >= 0.0 && (0.0..=1.0).contains(&along)).then_some((distance, normal))
+}
+
+fn cast_circle(ray: &Ray, center: Point, radius: f32) -> Option<(f32, Point)> {
+    let offset = sub(fcoord(ray.origin), center);
+    let half_b = dot(offset, ray.direction);
+    let c = dot(offset, offset) - radius * radius;
+    let discriminant = half_b * half_b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let distance = [-half_b - root, -half_b + root]
+        .into_iter()
+        .find(|distance| *distance >= 0.0)?;
+    let point = add(fcoord(ray.origin), scale(ray.direction, distance));
+    Some((distance, normalise(sub(point, center))))
+}
+
+/// Solved as a circle after moving the ray into the ellipse's unrotated, unscaled space
+fn cast_ellipse(ellipse: &Ellipse, ray: &Ray) -> Option<(f32, Point)> {
+    let (width, height) = ellipse.radii();
+    if width == 0.0 || height == 0.0 {
+        return None;
+    }
+    let rads = (ellipse.angle() as f32).to_radians();
+    let (sin, cos) = rads.sin_cos();
+    let to_local = |(x, y): Point| ((x * cos + y * sin) / width, (y * cos - x * sin) / height);
+    let origin = to_local(sub(fcoord(ray.origin), fcoord(ellipse.center())));
+    let direction = to_local(ray.direction);
+    let a = dot(direction, direction);
+    let half_b = dot(origin, direction);
+    let c = dot(origin, origin) - 1.0;
+    let discriminant = half_b * half_b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let distance = [(-half_b - root) / a, (-half_b + root) / a]
+        .into_iter()
+        .find(|distance| *distance >= 0.0)?;
+    let local = add(origin, scale(direction, distance));
+    let (x, y) = (local.0 / width, local.1 / height);
+    let normal = normalise((x * cos - y * sin, x * sin + y * cos));
+    Some((distance, normal))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::shape_box::ShapeBox;
+    use crate::shape_group::ShapeGroup;
+
+    fn close(actual: (f32, f32), expected: (f32, f32)) -> bool {
+        (actual.0 - expected.0).abs() < 0.001 && (actual.1 - expected.1).abs() < 0.001
+    }
+
+    #[test]
+    fn rect() {
+        let rect = Rect::new((10, 0), (20, 10));
+        assert!(rect.raycast(&Ray::new((0, 5), (-1.0, 0.0))).is_none());
+        assert!(rect.raycast(&Ray::new((0, 20), (1.0, 0.0))).is_none());
+        let hit = rect.raycast(&Ray::new((15, 30), (0.0, -2.0))).unwrap();
+        assert_eq!(hit.point(), coord!(15, 10));
+        assert_eq!(hit.distance(), 20.0);
+        assert_eq!(hit.normal(), (0.0, 1.0));
+
+        // from inside
+        let hit = rect.raycast(&Ray::new((15, 5), (1.0, 0.0))).unwrap();
+        assert_eq!(hit.point(), coord!(20, 5));
+        assert_eq!(hit.normal(), (-1.0, 0.0));
+    }
+
+    #[test]
+    fn max_distance() {
+        let rect = Rect::new((10, 0), (20, 10));
+        let ray = Ray::new((0, 5), (1.0, 0.0));
+        assert!(rect.raycast(&ray.with_max_distance(9.0)).is_none());
+        assert!(rect.raycast(&ray.with_max_distance(10.0)).is_some());
+        assert!(rect.raycast(&Ray::between((0, 5), (5, 5))).is_none());
+        assert!(rect.raycast(&Ray::between((0, 5), (30, 5))).is_some());
+    }
+
+    #[test]
+    fn circle() {
+        let circle = Circle::new((0, 0), 10);
+        let hit = circle.raycast(&Ray::new((-20, 0), (1.0, 0.0))).unwrap();
+        assert_eq!(hit.point(), coord!(-10, 0));
+        assert_eq!(hit.distance(), 10.0);
+        assert_eq!(hit.normal(), (-1.0, 0.0));
+        let hit = circle.raycast(&Ray::from_angle((0, 0), 90)).unwrap();
+        assert_eq!(hit.point(), coord!(10, 0));
+        assert!(close(hit.normal(), (-1.0, 0.0)));
+        assert!(circle.raycast(&Ray::new((-20, 11), (1.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn line() {
+        let line = Line::new((10, -10), (10, 10));
+        let hit = line.raycast(&Ray::new((0, 0), (1.0, 0.0))).unwrap();
+        assert_eq!(hit.point(), coord!(10, 0));
+        assert_eq!(hit.normal(), (-1.0, 0.0));
+        let hit = line.raycast(&Ray::new((20, 0), (-1.0, 0.0))).unwrap();
+        assert_eq!(hit.normal(), (1.0, 0.0));
+        let hit = line.raycast(&Ray::new((10, 20), (0.0, -1.0))).unwrap();
+        assert_eq!(hit.point(), coord!(10, 10));
+        assert_eq!(hit.distance(), 10.0);
+        assert!(line.raycast(&Ray::new((0, 0), (0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn triangle_and_polygon() {
+        let triangle = Triangle::new((0, 0), (20, 0), (0, 20));
+        let hit = triangle.raycast(&Ray::new((20, 20), (-1.0, -1.0))).unwrap();
+        assert_eq!(hit.point(), coord!(10, 10));
+        assert!(close(hit.normal(), (0.70710677, 0.70710677)));
+
+        let polygon = Polygon::regular((0, 0), 10, 6, 0);
+        let hit = polygon.raycast(&Ray::new((0, -30), (0.0, 1.0))).unwrap();
+        assert_eq!(hit.point(), coord!(0, -10));
+        assert_eq!(hit.distance(), 20.0);
+    }
+
+    #[test]
+    fn ellipse() {
+        let ellipse = Ellipse::new((0, 0), 40, 20);
+        let hit = ellipse.raycast(&Ray::new((-30, 0), (1.0, 0.0))).unwrap();
+        assert_eq!(hit.point(), coord!(-20, 0));
+        assert!(close(hit.normal(), (-1.0, 0.0)));
+        let hit = ellipse.raycast(&Ray::new((0, 30), (0.0, -1.0))).unwrap();
+        assert_eq!(hit.point(), coord!(0, 10));
+        assert!(close(hit.normal(), (0.0, 1.0)));
+
+        let rotated = ellipse.rotate(90);
+        let hit = rotated.raycast(&Ray::new((0, 30), (0.0, -1.0))).unwrap();
+        assert_eq!(hit.point(), coord!(0, 20));
+        assert!(close(hit.normal(), (0.0, 1.0)));
+
+        let rotated = ellipse.rotate(30);
+        let outline = rotated.as_polygon();
+        for degrees in (0..360).step_by(45) {
+            let ray = Ray::between(Coord::from_angle((0, 0), 50, degrees), (0, 0));
+            let hit = rotated.raycast(&ray).unwrap();
+            assert!(
+                outline.distance_to_point(hit.point()).abs() <= 2.0,
+                "{degrees}"
+            );
+            assert!(
+                rotated.signed_distance(hit.point()).abs() <= 1.0,
+                "{degrees}"
+            );
+        }
+    }
+
+    #[test]
+    fn nearest() {
+        let shapes = vec![
+            ShapeBox::from(Rect::new((30, -5), (40, 5))),
+            ShapeBox::from(Circle::new((15, 0), 3)),
+            ShapeBox::from(Line::new((50, -5), (50, 5))),
+        ];
+        let ray = Ray::new((0, 0), (1.0, 0.0));
+        let (index, hit) = raycast_nearest(&ray, &shapes).unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(hit.point(), coord!(12, 0));
+        assert!(raycast_nearest(&Ray::new((0, 0), (-1.0, 0.0)), &shapes).is_none());
+
+        let group = ShapeGroup::new(&shapes);
+        assert_eq!(group.raycast(&ray), Some(hit));
+        assert_eq!(ShapeBox::from(group).raycast(&ray), Some(hit));
+    }
+
+    #[test]
+    fn annulus() {
+        let annulus = Annulus::new((0, 0), 10, 20);
+        let hit = annulus.raycast(&Ray::new((0, 0), (1.0, 0.0))).unwrap();
+        assert_eq!(hit.point(), coord!(10, 0));
+        assert_eq!(hit.normal(), (-1.0, 0.0));
+    }
+}
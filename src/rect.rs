@@ -39,6 +39,15 @@ impl Rect {
             bottom_right,
         }
     }
+
+    /// Create a square with each corner `radius` away from `center`
+    ///
+    /// Same as [Polygon::regular] with 4 sides and a rotation of 45 degrees
+    #[must_use]
+    pub fn regular<P: Into<Coord>>(center: P, radius: usize) -> Self {
+        let polygon = Polygon::regular(center, radius, 4, 45);
+        Rect::new(polygon.top_left(), polygon.bottom_right())
+    }
 }
 
 impl Rect {
@@ -162,6 +171,19 @@ impl Shape for Rect {
         output.into_iter().collect()
     }
 
+    fn convex_hull(&self) -> Polygon {
+        Polygon::convex_hull(&self.as_polygon().points())
+    }
+
+    fn signed_distance(&self, point: Coord) -> f32 {
+        let half_width = (self.right() - self.left()) as f32 / 2.0;
+        let half_height = (self.bottom() - self.top()) as f32 / 2.0;
+        let dx = (point.x as f32 - (self.left() as f32 + half_width)).abs() - half_width;
+        let dy = (point.y as f32 - (self.top() as f32 + half_height)).abs() - half_height;
+        let outside = (dx.max(0.0).powi(2) + dy.max(0.0).powi(2)).sqrt();
+        outside + dx.max(dy).min(0.0)
+    }
+
     fn to_shape_box(&self) -> ShapeBox {
         ShapeBox::Rect(self.clone())
     }
@@ -196,9 +218,12 @@ impl Rect {
     /// Same shape but represented as four points/lines instead of two points
     #[must_use]
     pub fn as_polygon(&self) -> Polygon {
-        let top_right = coord!(self.right(), self.top());
-        let bottom_left = coord!(self.left(), self.bottom());
-        Polygon::new(&[self.top_left, top_right, self.bottom_right, bottom_left])
+        Polygon::new(&[
+            coord!(self.left(), self.top()),
+            coord!(self.right(), self.top()),
+            coord!(self.right(), self.bottom()),
+            coord!(self.left(), self.bottom()),
+        ])
     }
 
     #[must_use]
@@ -297,6 +322,15 @@ mod test {
         );
     }
 
+    #[test]
+    fn regular() {
+        let rect = Rect::regular((100, 100), 20);
+        assert!(rect.is_square());
+        assert_eq!(rect.top_left(), coord!(86, 86));
+        assert_eq!(rect.bottom_right(), coord!(114, 114));
+        assert_eq!(rect.center(), coord!(100, 100));
+    }
+
     #[test]
     fn move_center() {
         let rect = Rect::new((100, 100), (120, 120));
@@ -305,4 +339,25 @@ mod test {
         assert_eq!(rect.center(), coord!(110, 110));
         assert_eq!(moved.center(), coord!(50, 50));
     }
+
+    #[test]
+    fn convex_hull() {
+        let rect = Rect::new((10, 20), (40, 30));
+        let hull = rect.convex_hull();
+        assert!(hull.is_convex());
+        assert_eq!(hull.points().len(), 4);
+        assert_eq!(hull.area(), 300.0);
+        assert_eq!(rect.to_shape_box().convex_hull(), hull);
+    }
+
+    #[test]
+    fn contains_circle() {
+        let rect = Rect::new((0, 0), (100, 100));
+        assert!(rect.contains_circle(&Circle::new((50, 50), 10)));
+        assert!(rect.contains_circle(&Circle::new((50, 50), 50)));
+        assert!(rect.contains_circle(&Circle::new((10, 10), 10)));
+        assert!(!rect.contains_circle(&Circle::new((50, 50), 51)));
+        assert!(!rect.contains_circle(&Circle::new((5, 50), 10)));
+        assert!(!rect.contains_circle(&Circle::new((150, 50), 10)));
+    }
 }
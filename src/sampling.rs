@@ -0,0 +1,602 @@
+//! Random points inside and on the outline of shapes
+//!
+//! Requires the `rand` feature
+
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+use crate::shape_group::ShapeGroup;
+use rand::Rng;
+use std::f32::consts::TAU;
+
+/// How many times to retry when sampling by rejection before giving up
+const MAX_ATTEMPTS: usize = 1000;
+/// How many candidates are tried around each point for [poisson_disk]
+const POISSON_CANDIDATES: usize = 30;
+
+type Point = (f32, f32);
+
+/// Random points with a uniform distribution
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+///# use graphics_shapes::sampling::Sample;
+/// let circle = Circle::new((50, 50), 20);
+/// let mut rng = rand::thread_rng();
+/// let point = circle.sample_interior(&mut rng);
+/// assert!(circle.contains(point));
+/// ```
+pub trait Sample: Shape {
+    /// Random point inside the shape, every area of the shape is equally likely
+    #[must_use]
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord;
+
+    /// Random point on the outline of the shape, every part of the outline is equally likely
+    #[must_use]
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord;
+}
+
+impl Sample for Line {
+    /// Same as [sample_boundary](Sample::sample_boundary)
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        self.sample_boundary(rng)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        round(lerp(fcoord(self.start()), fcoord(self.end()), rng.gen()))
+    }
+}
+
+impl Sample for Rect {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        coord!(
+            rng.gen_range(self.left()..=self.right()),
+            rng.gen_range(self.top()..=self.bottom())
+        )
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        sample_ring(&self.as_polygon().points(), rng)
+    }
+}
+
+impl Sample for Triangle {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let points: Vec<Point> = self.points().into_iter().map(fcoord).collect();
+        round(sample_triangle(points[0], points[1], points[2], rng))
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        sample_ring(&self.points(), rng)
+    }
+}
+
+impl Sample for Circle {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        // sqrt so points aren't bunched up in the middle
+        let distance = self.radius() as f32 * rng.gen::<f32>().sqrt();
+        round(on_circle(
+            fcoord(self.center()),
+            distance,
+            rng.gen::<f32>() * TAU,
+        ))
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        round(on_circle(
+            fcoord(self.center()),
+            self.radius() as f32,
+            rng.gen::<f32>() * TAU,
+        ))
+    }
+}
+
+impl Sample for Ellipse {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let distance = rng.gen::<f32>().sqrt();
+        let angle = rng.gen::<f32>() * TAU;
+        self.unit_circle_point(distance * angle.cos(), distance * angle.sin())
+    }
+
+    /// Angles are rejected based on how stretched the outline is there,
+    /// so the points are spread evenly along the outline
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let (width, height) = self.radii();
+        let longest = width.max(height);
+        let mut angle = 0.0;
+        for _ in 0..MAX_ATTEMPTS {
+            angle = rng.gen::<f32>() * TAU;
+            let stretch = ((width * angle.sin()).powi(2) + (height * angle.cos()).powi(2)).sqrt();
+            if rng.gen::<f32>() * longest <= stretch {
+                break;
+            }
+        }
+        self.unit_circle_point(angle.cos(), angle.sin())
+    }
+}
+
+impl Ellipse {
+    fn unit_circle_point(&self, x: f32, y: f32) -> Coord {
+        let (width, height) = self.radii();
+        let (sin, cos) = (self.angle() as f32).to_radians().sin_cos();
+        let (x, y) = (x * width, y * height);
+        let center = fcoord(self.center());
+        round((center.0 + x * cos - y * sin, center.1 + x * sin + y * cos))
+    }
+}
+
+impl Sample for Polygon {
+    /// Triangles of the polygon are picked based on their area
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let points: Vec<Point> = self.points().into_iter().map(fcoord).collect();
+        let triangles = triangulate(&points);
+        let areas: Vec<f32> = triangles
+            .iter()
+            .map(|[a, b, c]| triangle_area(*a, *b, *c))
+            .collect();
+        match pick_weighted(&areas, rng) {
+            Some(i) => {
+                let [a, b, c] = triangles[i];
+                round(sample_triangle(a, b, c, rng))
+            }
+            None => sample_ring(&self.points(), rng),
+        }
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        sample_ring(&self.points(), rng)
+    }
+}
+
+impl Sample for Annulus {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let inner = (self.inner_radius() as f32).powi(2);
+        let outer = (self.outer_radius() as f32).powi(2);
+        let distance = (inner + rng.gen::<f32>() * (outer - inner)).sqrt();
+        round(on_circle(
+            fcoord(self.center()),
+            distance,
+            rng.gen::<f32>() * TAU,
+        ))
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let inner = self.inner_radius() as f32;
+        let outer = self.outer_radius() as f32;
+        let radius = if rng.gen::<f32>() * (inner + outer) < inner {
+            inner
+        } else {
+            outer
+        };
+        round(on_circle(
+            fcoord(self.center()),
+            radius,
+            rng.gen::<f32>() * TAU,
+        ))
+    }
+}
+
+impl Sample for Capsule {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        sample_by_rejection(self, rng)
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let start = fcoord(self.start());
+        let end = fcoord(self.end());
+        let radius = self.radius() as f32;
+        let spine = (end.0 - start.0, end.1 - start.1);
+        let length = (spine.0 * spine.0 + spine.1 * spine.1).sqrt();
+        let angle = spine.1.atan2(spine.0);
+        let side = (
+            -spine.1 / length.max(f32::EPSILON),
+            spine.0 / length.max(f32::EPSILON),
+        );
+        let arc = std::f32::consts::PI * radius;
+        let mut position = rng.gen::<f32>() * (2.0 * length + 2.0 * arc);
+        let offset =
+            |point: Point, amount: f32| (point.0 + side.0 * amount, point.1 + side.1 * amount);
+        if position < length {
+            return round(offset(lerp(start, end, position / length), radius));
+        }
+        position -= length;
+        if position < length {
+            return round(offset(lerp(start, end, position / length), -radius));
+        }
+        position -= length;
+        // half circles around each end
+        let turn = (position % arc) / radius;
+        if position < arc {
+            round(on_circle(end, radius, angle - TAU / 4.0 + turn))
+        } else {
+            round(on_circle(start, radius, angle + TAU / 4.0 + turn))
+        }
+    }
+}
+
+impl Sample for PolygonWithHoles {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let mut point = self.outer().sample_interior(rng);
+        for _ in 0..MAX_ATTEMPTS {
+            if !self.holes().iter().any(|hole| hole.contains(point)) {
+                break;
+            }
+            point = self.outer().sample_interior(rng);
+        }
+        point
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        sample_lines(&self.as_lines(), rng)
+    }
+}
+
+impl Sample for ShapeGroup {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        sample_by_rejection(self, rng)
+    }
+
+    /// Shapes are picked based on the length of their outlines
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        let lengths: Vec<f32> = self
+            .shapes()
+            .iter()
+            .map(|shape| shape.outline_pixels().len() as f32)
+            .collect();
+        match pick_weighted(&lengths, rng) {
+            Some(i) => self.shapes()[i].sample_boundary(rng),
+            None => self.center(),
+        }
+    }
+}
+
+impl Sample for ShapeBox {
+    fn sample_interior<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        match self {
+            ShapeBox::Line(shape) => shape.sample_interior(rng),
+            ShapeBox::Rect(shape) => shape.sample_interior(rng),
+            ShapeBox::Triangle(shape) => shape.sample_interior(rng),
+            ShapeBox::Circle(shape) => shape.sample_interior(rng),
+            ShapeBox::Ellipse(shape) => shape.sample_interior(rng),
+            ShapeBox::Polygon(shape) => shape.sample_interior(rng),
+            ShapeBox::Annulus(shape) => shape.sample_interior(rng),
+            ShapeBox::Capsule(shape) => shape.sample_interior(rng),
+            ShapeBox::PolygonWithHoles(shape) => shape.sample_interior(rng),
+            ShapeBox::Group(shape) => shape.sample_interior(rng),
+        }
+    }
+
+    fn sample_boundary<R: Rng + ?Sized>(&self, rng: &mut R) -> Coord {
+        match self {
+            ShapeBox::Line(shape) => shape.sample_boundary(rng),
+            ShapeBox::Rect(shape) => shape.sample_boundary(rng),
+            ShapeBox::Triangle(shape) => shape.sample_boundary(rng),
+            ShapeBox::Circle(shape) => shape.sample_boundary(rng),
+            ShapeBox::Ellipse(shape) => shape.sample_boundary(rng),
+            ShapeBox::Polygon(shape) => shape.sample_boundary(rng),
+            ShapeBox::Annulus(shape) => shape.sample_boundary(rng),
+            ShapeBox::Capsule(shape) => shape.sample_boundary(rng),
+            ShapeBox::PolygonWithHoles(shape) => shape.sample_boundary(rng),
+            ShapeBox::Group(shape) => shape.sample_boundary(rng),
+        }
+    }
+}
+
+/// Points inside `shape` that are at least `min_distance` apart but otherwise evenly spread out
+/// (using Bridson's algorithm)
+///
+/// Useful for scattering objects that shouldn't overlap, such as trees or spawn points
+#[must_use]
+pub fn poisson_disk<R: Rng + ?Sized>(
+    shape: &dyn Shape,
+    min_distance: f32,
+    rng: &mut R,
+) -> Vec<Coord> {
+    let min_distance = min_distance.max(1.0);
+    let cell = min_distance / 2.0_f32.sqrt();
+    let (left, top) = (shape.left() as f32, shape.top() as f32);
+    let columns = ((shape.right() - shape.left()) as f32 / cell) as usize + 1;
+    let rows = ((shape.bottom() - shape.top()) as f32 / cell) as usize + 1;
+    let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+    let cell_of = |point: Point| {
+        let x = (((point.0 - left) / cell) as usize).min(columns - 1);
+        let y = (((point.1 - top) / cell) as usize).min(rows - 1);
+        (x, y)
+    };
+
+    let mut points: Vec<Point> = vec![];
+    let mut active = vec![];
+    if let Some(first) = (0..MAX_ATTEMPTS)
+        .map(|_| random_in_bounds(shape, rng))
+        .find(|point| shape.contains(round(*point)))
+    {
+        let (x, y) = cell_of(first);
+        grid[y * columns + x] = Some(0);
+        points.push(first);
+        active.push(0);
+    }
+
+    while !active.is_empty() {
+        let index = rng.gen_range(0..active.len());
+        let origin = points[active[index]];
+        let mut found = false;
+        for _ in 0..POISSON_CANDIDATES {
+            let distance = rng.gen_range(min_distance..min_distance * 2.0);
+            let candidate = on_circle(origin, distance, rng.gen::<f32>() * TAU);
+            if candidate.0 < left
+                || candidate.1 < top
+                || candidate.0 > shape.right() as f32
+                || candidate.1 > shape.bottom() as f32
+                || !shape.contains(round(candidate))
+            {
+                continue;
+            }
+            let (x, y) = cell_of(candidate);
+            let too_close = (y.saturating_sub(2)..(y + 3).min(rows)).any(|y| {
+                (x.saturating_sub(2)..(x + 3).min(columns)).any(|x| {
+                    grid[y * columns + x]
+                        .map(|i| {
+                            let other = points[i];
+                            (other.0 - candidate.0).powi(2) + (other.1 - candidate.1).powi(2)
+                                < min_distance * min_distance
+                        })
+                        .unwrap_or(false)
+                })
+            });
+            if !too_close {
+                grid[y * columns + x] = Some(points.len());
+                active.push(points.len());
+                points.push(candidate);
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            active.swap_remove(index);
+        }
+    }
+    points.into_iter().map(round).collect()
+}
+
+fn random_in_bounds<R: Rng + ?Sized>(shape: &dyn Shape, rng: &mut R) -> Point {
+    (
+        shape.left() as f32 + rng.gen::<f32>() * (shape.right() - shape.left()) as f32,
+        shape.top() as f32 + rng.gen::<f32>() * (shape.bottom() - shape.top()) as f32,
+    )
+}
+
+fn sample_by_rejection<R: Rng + ?Sized>(shape: &dyn Shape, rng: &mut R) -> Coord {
+    (0..MAX_ATTEMPTS)
+        .map(|_| round(random_in_bounds(shape, rng)))
+        .find(|point| shape.contains(*point))
+        .unwrap_or_else(|| shape.center())
+}
+
+fn sample_ring<R: Rng + ?Sized>(points: &[Coord], rng: &mut R) -> Coord {
+    let lines: Vec<Line> = (0..points.len())
+        .map(|i| Line::new(points[i], points[(i + 1) % points.len()]))
+        .collect();
+    sample_lines(&lines, rng)
+}
+
+/// Lines are picked based on their length
+fn sample_lines<R: Rng + ?Sized>(lines: &[Line], rng: &mut R) -> Coord {
+    let lengths: Vec<f32> = lines
+        .iter()
+        .map(|line| {
+            let diff = line.end() - line.start();
+            ((diff.x * diff.x + diff.y * diff.y) as f32).sqrt()
+        })
+        .collect();
+    match pick_weighted(&lengths, rng) {
+        Some(i) => lines[i].sample_boundary(rng),
+        None => lines.first().map(|line| line.start()).unwrap_or_default(),
+    }
+}
+
+/// Index of a random item, more likely to be picked the larger its weight
+fn pick_weighted<R: Rng + ?Sized>(weights: &[f32], rng: &mut R) -> Option<usize> {
+    let total: f32 = weights.iter().sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut target = rng.gen::<f32>() * total;
+    for (i, weight) in weights.iter().enumerate() {
+        if target < *weight {
+            return Some(i);
+        }
+        target -= weight;
+    }
+    weights.iter().rposition(|weight| *weight > 0.0)
+}
+
+fn sample_triangle<R: Rng + ?Sized>(a: Point, b: Point, c: Point, rng: &mut R) -> Point {
+    let (mut u, mut v) = (rng.gen::<f32>(), rng.gen::<f32>());
+    if u + v > 1.0 {
+        // fold back into the triangle
+        u = 1.0 - u;
+        v = 1.0 - v;
+    }
+    (
+        a.0 + (b.0 - a.0) * u + (c.0 - a.0) * v,
+        a.1 + (b.1 - a.1) * u + (c.1 - a.1) * v,
+    )
+}
+
+fn triangle_area(a: Point, b: Point, c: Point) -> f32 {
+    ((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)).abs() / 2.0
+}
+
+/// Split a simple polygon (convex or concave) into triangles by ear clipping
+fn triangulate(points: &[Point]) -> Vec<[Point; 3]> {
+    let mut remaining = points.to_vec();
+    let area: f32 = (0..remaining.len())
+        .map(|i| {
+            let (a, b) = (remaining[i], remaining[(i + 1) % remaining.len()]);
+            a.0 * b.1 - a.1 * b.0
+        })
+        .sum();
+    let sign = area.signum();
+    let cross = |a: Point, b: Point, c: Point| {
+        ((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)) * sign
+    };
+    let mut output = vec![];
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let ear = (0..len).find(|&i| {
+            let (a, b, c) = (
+                remaining[(i + len - 1) % len],
+                remaining[i],
+                remaining[(i + 1) % len],
+            );
+            cross(a, b, c) > 0.0
+                && remaining.iter().all(|&p| {
+                    p == a
+                        || p == b
+                        || p == c
+                        || !(cross(a, b, p) >= 0.0
+                            && cross(b, c, p) >= 0.0
+                            && cross(c, a, p) >= 0.0)
+                })
+        });
+        // no ear means the polygon crosses itself, use what's left as a fan
+        let i = ear.unwrap_or(0);
+        output.push([
+            remaining[(i + len - 1) % len],
+            remaining[i],
+            remaining[(i + 1) % len],
+        ]);
+        remaining.remove(i);
+    }
+    if remaining.len() == 3 {
+        output.push([remaining[0], remaining[1], remaining[2]]);
+    }
+    output
+}
+
+fn on_circle(center: Point, radius: f32, radians: f32) -> Point {
+    (
+        center.0 + radius * radians.cos(),
+        center.1 + radius * radians.sin(),
+    )
+}
+
+fn lerp(start: Point, end: Point, amount: f32) -> Point {
+    (
+        start.0 + (end.0 - start.0) * amount,
+        start.1 + (end.1 - start.1) * amount,
+    )
+}
+
+fn fcoord(point: Coord) -> Point {
+    (point.x as f32, point.y as f32)
+}
+
+fn round(point: Point) -> Coord {
+    coord!(point.0.round(), point.1.round())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(42)
+    }
+
+    fn check_interior<S: Sample>(shape: &S) {
+        let mut rng = rng();
+        for _ in 0..500 {
+            let point = shape.sample_interior(&mut rng);
+            assert!(
+                shape.contains(point) || shape.signed_distance(point) <= 1.0,
+                "{point:?}"
+            );
+        }
+    }
+
+    fn check_boundary<S: Sample>(shape: &S) {
+        let mut rng = rng();
+        for _ in 0..500 {
+            let point = shape.sample_boundary(&mut rng);
+            assert!(
+                shape.signed_distance(point).abs() <= 1.0,
+                "{point:?} {}",
+                shape.signed_distance(point)
+            );
+        }
+    }
+
+    #[test]
+    fn all_shapes() {
+        let shapes = [
+            ShapeBox::from(Line::new((0, 0), (30, 50))),
+            ShapeBox::from(Rect::new((10, 10), (50, 30))),
+            ShapeBox::from(Triangle::new((0, 0), (40, 10), (10, 50))),
+            ShapeBox::from(Circle::new((20, 20), 15)),
+            ShapeBox::from(Ellipse::new((50, 50), 60, 20)),
+            ShapeBox::from(Ellipse::new((50, 50), 60, 20).rotate(30)),
+            ShapeBox::from(Polygon::new(&[
+                (0, 0),
+                (40, 0),
+                (40, 40),
+                (20, 10),
+                (0, 40),
+            ])),
+            ShapeBox::from(Annulus::new((30, 30), 10, 20)),
+            ShapeBox::from(Capsule::new((0, 0), (40, 30), 8)),
+            ShapeBox::from(PolygonWithHoles::new(
+                Rect::new((0, 0), (40, 40)).as_polygon(),
+                &[Rect::new((10, 10), (30, 30)).as_polygon()],
+            )),
+            ShapeBox::from(ShapeGroup::new(&[
+                ShapeBox::from(Rect::new((0, 0), (10, 10))),
+                ShapeBox::from(Circle::new((40, 40), 5)),
+            ])),
+        ];
+        for shape in shapes {
+            check_interior(&shape);
+            check_boundary(&shape);
+        }
+    }
+
+    #[test]
+    fn circle_is_uniform() {
+        let circle = Circle::new((0, 0), 100);
+        let mut rng = rng();
+        let inner = (0..10000)
+            .map(|_| circle.sample_interior(&mut rng))
+            .filter(|point| point.distance((0, 0)) < 50)
+            .count();
+        // a quarter of the area is within half the radius
+        assert!((2200..2800).contains(&inner), "{inner}");
+    }
+
+    #[test]
+    fn concave_polygon_is_area_weighted() {
+        // wide bottom part is 4 times the area of the thin top part
+        let polygon = Polygon::new(&[(0, 0), (10, 0), (10, 40), (50, 40), (50, 80), (0, 80)]);
+        let mut rng = rng();
+        let top = (0..10000)
+            .map(|_| polygon.sample_interior(&mut rng))
+            .filter(|point| point.y < 40)
+            .count();
+        assert!((1600..2400).contains(&top), "{top}");
+    }
+
+    #[test]
+    fn poisson() {
+        let circle = Circle::new((50, 50), 40);
+        let points = poisson_disk(&circle, 8.0, &mut rng());
+        assert!(points.len() > 40, "{}", points.len());
+        for (i, point) in points.iter().enumerate() {
+            assert!(circle.contains(*point));
+            for other in &points[i + 1..] {
+                let diff = *other - *point;
+                assert!(((diff.x * diff.x + diff.y * diff.y) as f32).sqrt() >= 7.0);
+            }
+        }
+    }
+}
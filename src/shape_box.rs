@@ -1,4 +1,6 @@
+use crate::outline::Outline;
 use crate::prelude::*;
+use crate::shape_group::ShapeGroup;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +17,10 @@ pub enum ShapeBox {
     Circle(Circle),
     Ellipse(Ellipse),
     Polygon(Polygon),
+    Annulus(Annulus),
+    Capsule(Capsule),
+    PolygonWithHoles(PolygonWithHoles),
+    Group(ShapeGroup),
 }
 
 macro_rules! per_shape_0 {
@@ -26,6 +32,10 @@ macro_rules! per_shape_0 {
             ShapeBox::Triangle(shape) => $method(shape),
             ShapeBox::Ellipse(shape) => $method(shape),
             ShapeBox::Polygon(shape) => $method(shape),
+            ShapeBox::Annulus(shape) => $method(shape),
+            ShapeBox::Capsule(shape) => $method(shape),
+            ShapeBox::PolygonWithHoles(shape) => $method(shape),
+            ShapeBox::Group(shape) => $method(shape),
         }
     };
 }
@@ -39,6 +49,10 @@ macro_rules! per_shape_1 {
             ShapeBox::Triangle(shape) => $method(shape, $param1),
             ShapeBox::Ellipse(shape) => $method(shape, $param1),
             ShapeBox::Polygon(shape) => $method(shape, $param1),
+            ShapeBox::Annulus(shape) => $method(shape, $param1),
+            ShapeBox::Capsule(shape) => $method(shape, $param1),
+            ShapeBox::PolygonWithHoles(shape) => $method(shape, $param1),
+            ShapeBox::Group(shape) => $method(shape, $param1),
         }
     };
 }
@@ -62,6 +76,12 @@ impl Shape for ShapeBox {
             ShapeBox::Circle(_) => ShapeBox::Circle(Circle::from_points(points)),
             ShapeBox::Ellipse(_) => ShapeBox::Ellipse(Ellipse::from_points(points)),
             ShapeBox::Polygon(_) => ShapeBox::Polygon(Polygon::from_points(points)),
+            ShapeBox::Annulus(_) => ShapeBox::Annulus(Annulus::from_points(points)),
+            ShapeBox::Capsule(_) => ShapeBox::Capsule(Capsule::from_points(points)),
+            ShapeBox::PolygonWithHoles(polygon) => {
+                ShapeBox::PolygonWithHoles(polygon.rebuild(points))
+            }
+            ShapeBox::Group(group) => ShapeBox::Group(group.rebuild(points)),
         }
     }
 
@@ -77,6 +97,22 @@ impl Shape for ShapeBox {
         per_shape_0!(self, Shape::center)
     }
 
+    fn left(&self) -> isize {
+        per_shape_0!(self, Shape::left)
+    }
+
+    fn right(&self) -> isize {
+        per_shape_0!(self, Shape::right)
+    }
+
+    fn top(&self) -> isize {
+        per_shape_0!(self, Shape::top)
+    }
+
+    fn bottom(&self) -> isize {
+        per_shape_0!(self, Shape::bottom)
+    }
+
     fn outline_pixels(&self) -> Vec<Coord> {
         per_shape_0!(self, Shape::outline_pixels)
     }
@@ -85,6 +121,44 @@ impl Shape for ShapeBox {
         per_shape_0!(self, Shape::filled_pixels)
     }
 
+    fn convex_hull(&self) -> Polygon {
+        per_shape_0!(self, Shape::convex_hull)
+    }
+
+    fn buffer(&self, distance: usize) -> Polygon {
+        per_shape_1!(self, Shape::buffer, distance)
+    }
+
+    fn distance_to_point(&self, point: Coord) -> f32 {
+        crate::distance::distance_to_point(self, point)
+    }
+
+    fn signed_distance(&self, point: Coord) -> f32 {
+        per_shape_1!(self, Shape::signed_distance, point)
+    }
+
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        crate::ray::raycast(self, ray)
+    }
+
+    fn outline_length(&self) -> f32 {
+        Outline::new(self).length()
+    }
+
+    fn point_at_distance(&self, distance: f32) -> Coord {
+        Outline::new(self).point_at_distance(distance)
+    }
+
+    fn point_at_fraction(&self, fraction: f32) -> Coord {
+        let outline = Outline::new(self);
+        outline.point_at_distance(outline.length() * fraction)
+    }
+
+    fn tangent_at(&self, fraction: f32) -> (f32, f32) {
+        let outline = Outline::new(self);
+        outline.tangent_at_distance(outline.length() * fraction)
+    }
+
     /// Same as `clone()`
     fn to_shape_box(&self) -> ShapeBox {
         self.clone()
@@ -115,6 +189,22 @@ impl IntersectsShape for ShapeBox {
     fn intersects_polygon(&self, polygon: &Polygon) -> bool {
         per_shape_1!(self, IntersectsShape::intersects_polygon, polygon)
     }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        per_shape_1!(self, IntersectsShape::intersects_annulus, annulus)
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        per_shape_1!(self, IntersectsShape::intersects_capsule, capsule)
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        per_shape_1!(
+            self,
+            IntersectsShape::intersects_polygon_with_holes,
+            polygon
+        )
+    }
 }
 
 impl ContainsShape for ShapeBox {
@@ -141,6 +231,18 @@ impl ContainsShape for ShapeBox {
     fn contains_polygon(&self, polygon: &Polygon) -> bool {
         per_shape_1!(self, ContainsShape::contains_polygon, polygon)
     }
+
+    fn contains_annulus(&self, annulus: &Annulus) -> bool {
+        per_shape_1!(self, ContainsShape::contains_annulus, annulus)
+    }
+
+    fn contains_capsule(&self, capsule: &Capsule) -> bool {
+        per_shape_1!(self, ContainsShape::contains_capsule, capsule)
+    }
+
+    fn contains_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        per_shape_1!(self, ContainsShape::contains_polygon_with_holes, polygon)
+    }
 }
 
 impl IntersectsContains for ShapeBox {}
@@ -161,6 +263,10 @@ shapebox_shape!(Triangle, ShapeBox::Triangle);
 shapebox_shape!(Circle, ShapeBox::Circle);
 shapebox_shape!(Ellipse, ShapeBox::Ellipse);
 shapebox_shape!(Polygon, ShapeBox::Polygon);
+shapebox_shape!(Annulus, ShapeBox::Annulus);
+shapebox_shape!(Capsule, ShapeBox::Capsule);
+shapebox_shape!(PolygonWithHoles, ShapeBox::PolygonWithHoles);
+shapebox_shape!(ShapeGroup, ShapeBox::Group);
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,469 @@
+use crate::new_hash_set;
+use crate::prelude::*;
+use crate::shape_box::ShapeBox;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Several shapes treated as one, so they can be moved, rotated and hit-tested together
+///
+/// Transforms are applied to every shape around the same point (e.g. the center of the whole group)
+/// and `contains` is true if any of the shapes contain the point
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+///# use graphics_shapes::shape_box::ShapeBox;
+///# use graphics_shapes::shape_group::ShapeGroup;
+/// let group = ShapeGroup::new(&[
+///     ShapeBox::from(Rect::new((0, 0), (10, 10))),
+///     ShapeBox::from(Circle::new((30, 5), 5)),
+/// ]);
+/// assert_eq!(group.top_left(), coord!(0, 0));
+/// assert_eq!(group.bottom_right(), coord!(35, 10));
+/// let moved = group.translate_by(coord!(10, 10));
+/// assert!(moved.contains(coord!(40, 15)));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ShapeGroup {
+    shapes: Vec<ShapeBox>,
+}
+
+impl IntersectsContains for ShapeGroup {}
+
+impl ShapeGroup {
+    #[must_use]
+    pub fn new(shapes: &[ShapeBox]) -> Self {
+        Self {
+            shapes: shapes.to_vec(),
+        }
+    }
+}
+
+impl ShapeGroup {
+    #[inline]
+    #[must_use]
+    pub fn shapes(&self) -> &[ShapeBox] {
+        &self.shapes
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Add `shape` to the group
+    pub fn push<S: Into<ShapeBox>>(&mut self, shape: S) {
+        self.shapes.push(shape.into());
+    }
+
+    /// Rectangle that surrounds every shape in the group
+    #[must_use]
+    pub fn as_outer_rect(&self) -> Rect {
+        Rect::new((self.left(), self.top()), (self.right(), self.bottom()))
+    }
+
+    fn map<F: FnMut(&ShapeBox) -> ShapeBox>(&self, f: F) -> Self {
+        ShapeGroup {
+            shapes: self.shapes.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Shape for ShapeGroup {
+    /// Creates a group containing a single polygon
+    fn from_points(points: &[Coord]) -> Self
+    where
+        Self: Sized,
+    {
+        ShapeGroup::new(&[ShapeBox::Polygon(Polygon::from_points(points))])
+    }
+
+    /// `points` must be the same length and in the same order as [ShapeGroup::points]
+    fn rebuild(&self, points: &[Coord]) -> Self
+    where
+        Self: Sized,
+    {
+        let mut start = 0;
+        self.map(|shape| {
+            let end = start + shape.points().len();
+            let shape = shape.rebuild(&points[start..end]);
+            start = end;
+            shape
+        })
+    }
+
+    fn translate_by(&self, delta: Coord) -> Self {
+        self.map(|shape| shape.translate_by(delta))
+    }
+
+    /// Moves the top left of the group to `point`, empty groups are unchanged
+    fn move_to(&self, point: Coord) -> Self {
+        if self.shapes.is_empty() {
+            return self.clone();
+        }
+        self.translate_by(point - self.top_left())
+    }
+
+    fn contains(&self, point: Coord) -> bool {
+        self.shapes.iter().any(|shape| shape.contains(point))
+    }
+
+    /// Points of every shape, in order
+    fn points(&self) -> Vec<Coord> {
+        self.shapes
+            .iter()
+            .flat_map(|shape| shape.points())
+            .collect()
+    }
+
+    fn rotate_around(&self, degrees: isize, point: Coord) -> Self {
+        self.map(|shape| shape.rotate_around(degrees, point))
+    }
+
+    /// Center of the rectangle surrounding every shape
+    fn center(&self) -> Coord {
+        self.top_left().mid_point(self.bottom_right())
+    }
+
+    fn left(&self) -> isize {
+        self.shapes
+            .iter()
+            .map(|shape| shape.left())
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn right(&self) -> isize {
+        self.shapes
+            .iter()
+            .map(|shape| shape.right())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn top(&self) -> isize {
+        self.shapes
+            .iter()
+            .map(|shape| shape.top())
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn bottom(&self) -> isize {
+        self.shapes
+            .iter()
+            .map(|shape| shape.bottom())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn scale_around(&self, factor: f32, point: Coord) -> Self {
+        self.map(|shape| shape.scale_around(factor, point))
+    }
+
+    fn outline_pixels(&self) -> Vec<Coord> {
+        let mut output = new_hash_set();
+        for shape in &self.shapes {
+            output.extend(shape.outline_pixels());
+        }
+        output.into_iter().collect()
+    }
+
+    fn filled_pixels(&self) -> Vec<Coord> {
+        let mut output = new_hash_set();
+        for shape in &self.shapes {
+            output.extend(shape.filled_pixels());
+        }
+        output.into_iter().collect()
+    }
+
+    /// Convex hull around every shape in the group
+    ///
+    /// Empty groups return a polygon with a single point at [center](Shape::center)
+    fn convex_hull(&self) -> Polygon {
+        if self.shapes.is_empty() {
+            return Polygon::new(&[self.center()]);
+        }
+        let points: Vec<Coord> = self
+            .shapes
+            .iter()
+            .flat_map(|shape| shape.convex_hull().points())
+            .collect();
+        Polygon::convex_hull(&points)
+    }
+
+    fn signed_distance(&self, point: Coord) -> f32 {
+        self.shapes
+            .iter()
+            .map(|shape| shape.signed_distance(point))
+            .fold(f32::MAX, f32::min)
+    }
+
+    fn to_shape_box(&self) -> ShapeBox {
+        ShapeBox::Group(self.clone())
+    }
+}
+
+/// True if any shape in the group intersects the other shape
+impl IntersectsShape for ShapeGroup {
+    fn intersects_rect(&self, rect: &Rect) -> bool {
+        self.shapes.iter().any(|shape| shape.intersects_rect(rect))
+    }
+
+    fn intersects_circle(&self, circle: &Circle) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.intersects_circle(circle))
+    }
+
+    fn intersects_line(&self, line: &Line) -> bool {
+        self.shapes.iter().any(|shape| shape.intersects_line(line))
+    }
+
+    fn intersects_triangle(&self, triangle: &Triangle) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.intersects_triangle(triangle))
+    }
+
+    fn intersects_ellipse(&self, ellipse: &Ellipse) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.intersects_ellipse(ellipse))
+    }
+
+    fn intersects_polygon(&self, polygon: &Polygon) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.intersects_polygon(polygon))
+    }
+
+    fn intersects_annulus(&self, annulus: &Annulus) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.intersects_annulus(annulus))
+    }
+
+    fn intersects_capsule(&self, capsule: &Capsule) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.intersects_capsule(capsule))
+    }
+
+    fn intersects_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.intersects_polygon_with_holes(polygon))
+    }
+}
+
+/// True if any single shape in the group contains the other shape
+///
+/// A shape that is only covered by several shapes together is not counted as contained
+impl ContainsShape for ShapeGroup {
+    fn contains_rect(&self, rect: &Rect) -> bool {
+        self.shapes.iter().any(|shape| shape.contains_rect(rect))
+    }
+
+    fn contains_circle(&self, circle: &Circle) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.contains_circle(circle))
+    }
+
+    fn contains_line(&self, line: &Line) -> bool {
+        self.shapes.iter().any(|shape| shape.contains_line(line))
+    }
+
+    fn contains_triangle(&self, triangle: &Triangle) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.contains_triangle(triangle))
+    }
+
+    fn contains_ellipse(&self, ellipse: &Ellipse) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.contains_ellipse(ellipse))
+    }
+
+    fn contains_polygon(&self, polygon: &Polygon) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.contains_polygon(polygon))
+    }
+
+    fn contains_annulus(&self, annulus: &Annulus) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.contains_annulus(annulus))
+    }
+
+    fn contains_capsule(&self, capsule: &Capsule) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.contains_capsule(capsule))
+    }
+
+    fn contains_polygon_with_holes(&self, polygon: &PolygonWithHoles) -> bool {
+        self.shapes
+            .iter()
+            .any(|shape| shape.contains_polygon_with_holes(polygon))
+    }
+}
+
+impl From<Vec<ShapeBox>> for ShapeGroup {
+    fn from(value: Vec<ShapeBox>) -> Self {
+        ShapeGroup { shapes: value }
+    }
+}
+
+impl FromIterator<ShapeBox> for ShapeGroup {
+    fn from_iter<T: IntoIterator<Item = ShapeBox>>(iter: T) -> Self {
+        ShapeGroup {
+            shapes: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::shape_box::ShapeBox;
+    use crate::shape_group::ShapeGroup;
+
+    fn group() -> ShapeGroup {
+        ShapeGroup::new(&[
+            ShapeBox::from(Rect::new((0, 0), (10, 10))),
+            ShapeBox::from(Circle::new((30, 5), 5)),
+        ])
+    }
+
+    #[test]
+    fn bounds() {
+        let group = group();
+        assert_eq!(group.left(), 0);
+        assert_eq!(group.top(), 0);
+        assert_eq!(group.right(), 35);
+        assert_eq!(group.bottom(), 10);
+        assert_eq!(group.center(), coord!(17, 5));
+        assert_eq!(
+            ShapeGroup::default().as_outer_rect(),
+            Rect::new((0, 0), (0, 0))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let group = group();
+        assert!(group.contains(coord!(5, 5)));
+        assert!(group.contains(coord!(30, 5)));
+        assert!(!group.contains(coord!(20, 5)));
+    }
+
+    #[test]
+    fn transform() {
+        let group = group();
+        let moved = group.move_to(coord!(10, 20));
+        assert_eq!(
+            moved.shapes(),
+            &[
+                ShapeBox::from(Rect::new((10, 20), (20, 30))),
+                ShapeBox::from(Circle::new((40, 25), 5)),
+            ]
+        );
+
+        let rotated = group.rotate_around(180, coord!(0, 0));
+        assert_eq!(rotated.shapes()[1].center(), coord!(-30, -5));
+        assert_eq!(rotated.left(), -35);
+        assert_eq!(rotated.right(), 0);
+
+        let scaled = group.scale_around(2.0, coord!(0, 0));
+        assert_eq!(
+            scaled.shapes()[0],
+            ShapeBox::from(Rect::new((0, 0), (20, 20)))
+        );
+        assert!(scaled.shapes()[1].center().distance((60, 10)) <= 1);
+    }
+
+    #[test]
+    fn convex_hull() {
+        let hull = group().convex_hull();
+        assert!(hull.is_convex());
+        assert_eq!(hull.left(), 0);
+        assert!(hull.right() >= 34);
+        assert!(hull.contains(coord!(20, 5)));
+        let boxed = group().to_shape_box().convex_hull();
+        assert_eq!(boxed, hull);
+    }
+
+    #[test]
+    fn empty_convex_hull() {
+        let empty = ShapeGroup::default();
+        assert_eq!(empty.convex_hull().points(), vec![empty.center()]);
+        assert_eq!(
+            ShapeGroup::new(&[]).to_shape_box().convex_hull().points(),
+            vec![coord!(0, 0)]
+        );
+        assert_eq!(empty.buffer(5).points().len(), 1);
+        let rect = ShapeBox::from(Rect::new((10, 10), (20, 20)));
+        assert!(empty.to_shape_box().collide(&rect).is_none());
+    }
+
+    #[test]
+    fn move_to() {
+        let empty = ShapeGroup::default();
+        assert_eq!(empty.move_to(coord!(5, 5)), empty);
+        let moved = group().move_to(coord!(5, 5));
+        assert_eq!(moved.top_left(), coord!(5, 5));
+        assert_eq!(moved.bottom_right(), coord!(40, 15));
+    }
+
+    #[test]
+    fn pixels() {
+        let overlapping = ShapeGroup::new(&[
+            ShapeBox::from(Rect::new((0, 0), (10, 10))),
+            ShapeBox::from(Rect::new((5, 5), (15, 15))),
+        ]);
+        let mut filled = overlapping.filled_pixels();
+        let count = filled.len();
+        filled.sort_by_key(|point| (point.x, point.y));
+        filled.dedup();
+        assert_eq!(filled.len(), count);
+        assert!(filled.contains(&coord!(12, 12)));
+        assert!(filled.contains(&coord!(2, 2)));
+    }
+
+    #[test]
+    fn hit_test() {
+        let group = group();
+        assert!(group.intersects_line(&Line::new((20, 5), (30, 5))));
+        assert!(!group.intersects_line(&Line::new((15, 0), (15, 10))));
+        assert!(group.contains_circle(&Circle::new((30, 5), 2)));
+        assert!(!group.contains_rect(&Rect::new((5, 5), (30, 5))));
+        assert_eq!(
+            group.intersects_shape(&Rect::new((8, 8), (28, 12))),
+            Some(true)
+        );
+        assert_eq!(
+            Rect::new((-5, -5), (50, 50)).contains_shape(&group),
+            Some(true)
+        );
+        assert_eq!(
+            Rect::new((-5, -5), (20, 20)).contains_shape(&group),
+            Some(false)
+        );
+        assert_eq!(
+            Line::new((12, 0), (12, 20)).intersects_shape(&group.to_shape_box()),
+            Some(false)
+        );
+    }
+}
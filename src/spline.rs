@@ -0,0 +1,421 @@
+//! Smooth curves through (or guided by) a series of points, for camera paths, motion, etc
+//!
+//! * [Spline::catmull_rom] passes through every point
+//! * [Spline::b_spline] passes through the first and last points and is pulled towards the others,
+//!   making it smoother
+//!
+//! Positions can be found by parameter ([Spline::point_at]), where points are spread evenly per
+//! control point, or by distance along the curve ([Spline::point_at_distance]), which gives
+//! constant speed movement
+//!
+//! # Usage
+//! ```rust
+//!# use graphics_shapes::prelude::*;
+//!# use graphics_shapes::spline::{Parametrisation, Spline};
+//! let path = Spline::catmull_rom(
+//!     &[coord!(0, 0), coord!(50, 20), coord!(100, 0)],
+//!     Parametrisation::Centripetal,
+//! );
+//! assert_eq!(path.point_at(0.5), coord!(50, 20));
+//! let polyline = path.to_polyline_by_length(20);
+//! assert_eq!(polyline.points().len(), 21);
+//! ```
+
+use crate::distance::{add, fcoord, length, round, scale, sub, Point};
+use crate::prelude::*;
+
+/// How many samples are used per section when measuring the length of a spline
+const SAMPLES_PER_SECTION: usize = 32;
+/// Smallest gap between knots, stops repeated points from dividing by zero
+const MIN_KNOT_GAP: f32 = 0.0001;
+
+/// How the curve is spread between the points
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Parametrisation {
+    /// Every point has the same weight, the curve can overshoot or loop when the points
+    /// are unevenly spaced
+    Uniform,
+    /// Weighted by the square root of the distance between points, this avoids loops and
+    /// cusps and keeps the curve close to the points
+    Centripetal,
+}
+
+impl Parametrisation {
+    fn alpha(&self) -> f32 {
+        match self {
+            Parametrisation::Uniform => 0.0,
+            Parametrisation::Centripetal => 0.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    CatmullRom,
+    /// Clamped B-spline, with the knot vector calculated from the points
+    BSpline {
+        degree: usize,
+        knots: Vec<f32>,
+    },
+}
+
+/// Curve through or near a list of points, see [module docs](self)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spline {
+    points: Vec<Coord>,
+    parametrisation: Parametrisation,
+    kind: Kind,
+    /// Length along the curve at evenly spaced parameters, used for [Spline::point_at_distance]
+    lengths: Vec<f32>,
+}
+
+impl Spline {
+    /// Curve that passes through every point in `points`
+    #[must_use]
+    pub fn catmull_rom(points: &[Coord], parametrisation: Parametrisation) -> Self {
+        Self::new(points, parametrisation, Kind::CatmullRom)
+    }
+
+    /// Smoother curve that starts at the first point, ends at the last point and is pulled
+    /// towards the other points without passing through them
+    ///
+    /// The curve is cubic if there are at least 4 points, otherwise it's quadratic (3 points)
+    /// or a line (2 points)
+    #[must_use]
+    pub fn b_spline(points: &[Coord], parametrisation: Parametrisation) -> Self {
+        let degree = points.len().saturating_sub(1).min(3);
+        let knots = b_spline_knots(points, degree, parametrisation);
+        Self::new(points, parametrisation, Kind::BSpline { degree, knots })
+    }
+
+    fn new(points: &[Coord], parametrisation: Parametrisation, kind: Kind) -> Self {
+        let mut spline = Spline {
+            points: points.to_vec(),
+            parametrisation,
+            kind,
+            lengths: vec![],
+        };
+        let samples = spline.sections() * SAMPLES_PER_SECTION;
+        let mut total = 0.0;
+        let mut previous = spline.evaluate(0.0);
+        spline.lengths.push(0.0);
+        for i in 1..=samples {
+            let point = spline.evaluate(i as f32 / samples as f32);
+            total += length(sub(point, previous));
+            spline.lengths.push(total);
+            previous = point;
+        }
+        spline
+    }
+}
+
+impl Spline {
+    #[inline]
+    #[must_use]
+    pub fn points(&self) -> &[Coord] {
+        &self.points
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn parametrisation(&self) -> Parametrisation {
+        self.parametrisation
+    }
+
+    /// Length along the curve
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.lengths.last().copied().unwrap_or_default()
+    }
+
+    /// Point on the curve at `t`, where `0.0` is the start and `1.0` is the end
+    ///
+    /// For Catmull-Rom splines each pair of points gets an equal part of `t`, so the curve
+    /// reaches every point at `index / (len - 1)`
+    ///
+    /// `t` is clamped to `0.0..=1.0`. If there are no points then (0, 0) is returned
+    #[must_use]
+    pub fn point_at(&self, t: f32) -> Coord {
+        round(self.evaluate(t))
+    }
+
+    /// Point `distance` pixels along the curve, `distance` is clamped to the length of the curve
+    #[must_use]
+    pub fn point_at_distance(&self, distance: f32) -> Coord {
+        round(self.evaluate(self.t_at_distance(distance)))
+    }
+
+    /// Point `fraction` of the way along the curve by distance, see [Spline::point_at_distance]
+    #[must_use]
+    pub fn point_at_fraction(&self, fraction: f32) -> Coord {
+        self.point_at_distance(self.length() * fraction)
+    }
+
+    /// Curve as `segments` lines, spread evenly by parameter, see [Spline::point_at]
+    #[must_use]
+    pub fn to_polyline(&self, segments: usize) -> Polyline {
+        self.sample(segments, |spline, fraction| spline.point_at(fraction))
+    }
+
+    /// Curve as `segments` lines of (roughly) equal length, see [Spline::point_at_distance]
+    #[must_use]
+    pub fn to_polyline_by_length(&self, segments: usize) -> Polyline {
+        self.sample(segments, Spline::point_at_fraction)
+    }
+
+    fn sample(&self, segments: usize, point_at: fn(&Spline, f32) -> Coord) -> Polyline {
+        let segments = segments.max(1);
+        let points: Vec<Coord> = (0..=segments)
+            .map(|i| point_at(self, i as f32 / segments as f32))
+            .collect();
+        Polyline::new(&points)
+    }
+
+    fn sections(&self) -> usize {
+        self.points.len().saturating_sub(1).max(1)
+    }
+
+    fn t_at_distance(&self, distance: f32) -> f32 {
+        let total = self.length();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let distance = distance.clamp(0.0, total);
+        let step = self
+            .lengths
+            .partition_point(|length| *length <= distance)
+            .clamp(1, self.lengths.len() - 1);
+        let (before, after) = (self.lengths[step - 1], self.lengths[step]);
+        let part = if after > before {
+            (distance - before) / (after - before)
+        } else {
+            0.0
+        };
+        ((step - 1) as f32 + part) / (self.lengths.len() - 1) as f32
+    }
+
+    fn evaluate(&self, t: f32) -> Point {
+        let t = t.clamp(0.0, 1.0);
+        match self.points.len() {
+            0 => (0.0, 0.0),
+            1 => fcoord(self.points[0]),
+            _ => match &self.kind {
+                Kind::CatmullRom => catmull_rom(&self.points, self.parametrisation.alpha(), t),
+                Kind::BSpline { degree, knots } => de_boor(&self.points, *degree, knots, t),
+            },
+        }
+    }
+}
+
+/// Position at `t` (across the whole curve) using the Barry-Goldman method
+fn catmull_rom(points: &[Coord], alpha: f32, t: f32) -> Point {
+    let sections = points.len() - 1;
+    let section = ((t * sections as f32) as usize).min(sections - 1);
+    let local = t * sections as f32 - section as f32;
+
+    // mirror the neighbours of the first and last points so the curve reaches them
+    let p1 = fcoord(points[section]);
+    let p2 = fcoord(points[section + 1]);
+    let p0 = if section == 0 {
+        sub(scale(p1, 2.0), p2)
+    } else {
+        fcoord(points[section - 1])
+    };
+    let p3 = points
+        .get(section + 2)
+        .map(|point| fcoord(*point))
+        .unwrap_or_else(|| sub(scale(p2, 2.0), p1));
+
+    let knot = |previous: f32, from: Point, to: Point| {
+        previous + length(sub(to, from)).powf(alpha).max(MIN_KNOT_GAP)
+    };
+    let t0 = 0.0;
+    let t1 = knot(t0, p0, p1);
+    let t2 = knot(t1, p1, p2);
+    let t3 = knot(t2, p2, p3);
+    let t = t1 + (t2 - t1) * local;
+
+    let mix = |a: Point, b: Point, start: f32, end: f32| {
+        add(
+            scale(a, (end - t) / (end - start)),
+            scale(b, (t - start) / (end - start)),
+        )
+    };
+    let a1 = mix(p0, p1, t0, t1);
+    let a2 = mix(p1, p2, t1, t2);
+    let a3 = mix(p2, p3, t2, t3);
+    let b1 = mix(a1, a2, t0, t2);
+    let b2 = mix(a2, a3, t1, t3);
+    mix(b1, b2, t1, t2)
+}
+
+/// Clamped knot vector, the interior knots are averages of the point parameters so the
+/// curve follows the spacing of the points
+fn b_spline_knots(points: &[Coord], degree: usize, parametrisation: Parametrisation) -> Vec<f32> {
+    if points.len() < 2 {
+        return vec![];
+    }
+    let mut params = vec![0.0];
+    for pair in points.windows(2) {
+        let gap = length(sub(fcoord(pair[1]), fcoord(pair[0]))).powf(parametrisation.alpha());
+        params.push(params[params.len() - 1] + gap);
+    }
+    let total = params[params.len() - 1];
+    if total > 0.0 {
+        params.iter_mut().for_each(|param| *param /= total);
+    } else {
+        let count = (params.len() - 1) as f32;
+        params
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, param)| *param = i as f32 / count);
+    }
+
+    let mut knots = vec![0.0; degree + 1];
+    for j in 1..points.len() - degree {
+        let sum: f32 = params[j..j + degree].iter().sum();
+        knots.push(sum / degree as f32);
+    }
+    knots.extend(vec![1.0; degree + 1]);
+    knots
+}
+
+/// Position at `t` using de Boor's algorithm
+fn de_boor(points: &[Coord], degree: usize, knots: &[f32], t: f32) -> Point {
+    let span = (degree..points.len())
+        .rev()
+        .find(|span| knots[*span] <= t && knots[*span] < 1.0)
+        .unwrap_or(degree);
+    let mut working: Vec<Point> = (0..=degree)
+        .map(|j| fcoord(points[j + span - degree]))
+        .collect();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let start = knots[j + span - degree];
+            let end = knots[j + 1 + span - r];
+            let amount = if end > start {
+                (t - start) / (end - start)
+            } else {
+                0.0
+            };
+            working[j] = add(
+                scale(working[j - 1], 1.0 - amount),
+                scale(working[j], amount),
+            );
+        }
+    }
+    working[degree]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn zig_zag() -> Vec<Coord> {
+        vec![
+            coord!(0, 0),
+            coord!(20, 40),
+            coord!(40, 0),
+            coord!(100, 40),
+            coord!(110, 0),
+        ]
+    }
+
+    #[test]
+    fn catmull_rom_passes_through_points() {
+        for parametrisation in [Parametrisation::Uniform, Parametrisation::Centripetal] {
+            let points = zig_zag();
+            let spline = Spline::catmull_rom(&points, parametrisation);
+            for (i, point) in points.iter().enumerate() {
+                assert_eq!(spline.point_at(i as f32 / 4.0), *point);
+            }
+            assert_eq!(spline.point_at(-1.0), points[0]);
+            assert_eq!(spline.point_at(2.0), points[4]);
+        }
+    }
+
+    #[test]
+    fn straight_lines() {
+        let points = [coord!(0, 0), coord!(10, 0), coord!(20, 0), coord!(30, 0)];
+        for spline in [
+            Spline::catmull_rom(&points, Parametrisation::Uniform),
+            Spline::b_spline(&points, Parametrisation::Uniform),
+        ] {
+            assert!((spline.length() - 30.0).abs() < 0.01);
+            assert_eq!(spline.point_at_distance(15.0), coord!(15, 0));
+            assert_eq!(spline.point_at_fraction(0.9), coord!(27, 0));
+            assert_eq!(spline.point_at_distance(100.0), coord!(30, 0));
+        }
+    }
+
+    #[test]
+    fn b_spline() {
+        let points = zig_zag();
+        let spline = Spline::b_spline(&points, Parametrisation::Centripetal);
+        assert_eq!(spline.point_at(0.0), points[0]);
+        assert_eq!(spline.point_at(1.0), points[4]);
+        // smoother than the points, so doesn't reach the peaks
+        let polyline = spline.to_polyline(50);
+        let lowest = polyline.points().iter().map(|point| point.y).max().unwrap();
+        assert!(lowest < 40);
+        assert!(spline.length() < Polyline::new(&points).length());
+
+        let line = Spline::b_spline(&[coord!(0, 0), coord!(10, 10)], Parametrisation::Uniform);
+        assert_eq!(line.point_at(0.5), coord!(5, 5));
+        let curve = Spline::b_spline(
+            &[coord!(0, 0), coord!(10, 20), coord!(20, 0)],
+            Parametrisation::Uniform,
+        );
+        assert_eq!(curve.point_at(0.5), coord!(10, 10));
+    }
+
+    #[test]
+    fn centripetal_avoids_overshoot() {
+        // a close pair of points makes uniform splines loop
+        let points = [
+            coord!(0, 0),
+            coord!(100, 0),
+            coord!(102, 2),
+            coord!(102, 100),
+        ];
+        let uniform = Spline::catmull_rom(&points, Parametrisation::Uniform);
+        let centripetal = Spline::catmull_rom(&points, Parametrisation::Centripetal);
+        let furthest_right = |spline: &Spline| {
+            spline
+                .to_polyline(300)
+                .points()
+                .iter()
+                .map(|point| point.x)
+                .max()
+                .unwrap()
+        };
+        assert!(furthest_right(&uniform) > furthest_right(&centripetal));
+        assert!(furthest_right(&centripetal) <= 104);
+    }
+
+    #[test]
+    fn even_spacing() {
+        let spline = Spline::catmull_rom(&zig_zag(), Parametrisation::Centripetal);
+        let polyline = spline.to_polyline_by_length(40);
+        assert_eq!(polyline.points().len(), 41);
+        let expected = spline.length() / 40.0;
+        for line in polyline.as_lines() {
+            assert!((line.len() as f32 - expected).abs() <= 2.0);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty = Spline::catmull_rom(&[], Parametrisation::Uniform);
+        assert_eq!(empty.point_at(0.5), coord!(0, 0));
+        assert_eq!(empty.length(), 0.0);
+        let single = Spline::b_spline(&[coord!(5, 5)], Parametrisation::Centripetal);
+        assert_eq!(single.point_at_distance(10.0), coord!(5, 5));
+        let repeated = Spline::catmull_rom(
+            &[coord!(0, 0), coord!(0, 0), coord!(10, 0)],
+            Parametrisation::Centripetal,
+        );
+        assert_eq!(repeated.point_at(1.0), coord!(10, 0));
+        assert!(!repeated.point_at(0.75).x.is_negative());
+    }
+}
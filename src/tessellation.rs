@@ -0,0 +1,313 @@
+//! Delaunay triangulation and Voronoi diagrams for sets of points
+
+use crate::prelude::*;
+
+type Point = (f64, f64);
+
+/// Triangle as indexes into the list of points, with its circumcircle
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    corners: [usize; 3],
+    center: Point,
+    radius_sq: f64,
+}
+
+impl Face {
+    fn new(corners: [usize; 3], points: &[Point]) -> Option<Self> {
+        let [a, b, c] = corners.map(|i| points[i]);
+        let d = 2.0 * (a.0 * (b.1 - c.1) + b.0 * (c.1 - a.1) + c.0 * (a.1 - b.1));
+        if d.abs() < f64::EPSILON {
+            return None;
+        }
+        let a_sq = a.0 * a.0 + a.1 * a.1;
+        let b_sq = b.0 * b.0 + b.1 * b.1;
+        let c_sq = c.0 * c.0 + c.1 * c.1;
+        let center = (
+            (a_sq * (b.1 - c.1) + b_sq * (c.1 - a.1) + c_sq * (a.1 - b.1)) / d,
+            (a_sq * (c.0 - b.0) + b_sq * (a.0 - c.0) + c_sq * (b.0 - a.0)) / d,
+        );
+        let radius_sq = (a.0 - center.0).powi(2) + (a.1 - center.1).powi(2);
+        Some(Face {
+            corners,
+            center,
+            radius_sq,
+        })
+    }
+
+    fn in_circumcircle(&self, point: Point) -> bool {
+        let dist_sq = (point.0 - self.center.0).powi(2) + (point.1 - self.center.1).powi(2);
+        dist_sq < self.radius_sq * (1.0 - 1e-12)
+    }
+
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.corners;
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+/// Split `points` into triangles where no point is inside any triangles circumcircle
+/// (using the Bowyer–Watson algorithm)
+///
+/// Duplicate points are ignored and if all the points are on a single line there are no triangles
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+///# use graphics_shapes::tessellation::delaunay;
+/// let triangles = delaunay(&[coord!(0, 0), coord!(10, 0), coord!(10, 10), coord!(0, 10), coord!(5, 4)]);
+/// assert_eq!(triangles.len(), 4);
+/// ```
+#[must_use]
+pub fn delaunay(points: &[Coord]) -> Vec<Triangle> {
+    let (unique, faces) = delaunay_faces(points);
+    faces
+        .iter()
+        .map(|face| {
+            let [a, b, c] = face.corners.map(|i| unique[i]);
+            Triangle::new(a, b, c)
+        })
+        .collect()
+}
+
+fn delaunay_faces(points: &[Coord]) -> (Vec<Coord>, Vec<Face>) {
+    let mut unique = points.to_vec();
+    unique.sort_by_key(|point| (point.x, point.y));
+    unique.dedup();
+    if unique.len() < 3 {
+        return (unique, vec![]);
+    }
+
+    let mut fpoints: Vec<Point> = unique
+        .iter()
+        .map(|point| (point.x as f64, point.y as f64))
+        .collect();
+    let (min_x, max_x, min_y, max_y) = fpoints.iter().fold(
+        (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+        |(min_x, max_x, min_y, max_y), point| {
+            (
+                min_x.min(point.0),
+                max_x.max(point.0),
+                min_y.min(point.1),
+                max_y.max(point.1),
+            )
+        },
+    );
+    let size = (max_x - min_x).max(max_y - min_y).max(1.0) * 20.0;
+    let mid = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+    let count = fpoints.len();
+    fpoints.push((mid.0 - size, mid.1 - size));
+    fpoints.push((mid.0 + size, mid.1 - size));
+    fpoints.push((mid.0, mid.1 + size));
+
+    let mut faces = vec![];
+    faces.extend(Face::new([count, count + 1, count + 2], &fpoints));
+    for i in 0..count {
+        let point = fpoints[i];
+        let (bad, good): (Vec<Face>, Vec<Face>) = faces
+            .into_iter()
+            .partition(|face| face.in_circumcircle(point));
+        faces = good;
+        let mut boundary: Vec<(usize, usize)> = vec![];
+        for (a, b) in bad.iter().flat_map(|face| face.edges()) {
+            if let Some(pos) = boundary.iter().position(|edge| *edge == (b, a)) {
+                boundary.swap_remove(pos);
+            } else {
+                boundary.push((a, b));
+            }
+        }
+        faces.extend(
+            boundary
+                .into_iter()
+                .filter_map(|(a, b)| Face::new([a, b, i], &fpoints)),
+        );
+    }
+    faces.retain(|face| face.corners.iter().all(|i| *i < count));
+    (unique, faces)
+}
+
+/// Split `bounds` into one cell per point, every position in a cell is closer to that point than any other point
+///
+/// The cells are returned in the same order as `points`, duplicate points have the same cell
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+///# use graphics_shapes::tessellation::voronoi;
+/// let cells = voronoi(&[coord!(25, 50), coord!(75, 50)], Rect::new((0, 0), (100, 100)));
+/// assert_eq!(cells[0].right(), 50);
+/// assert_eq!(cells[1].left(), 50);
+/// ```
+#[must_use]
+pub fn voronoi(points: &[Coord], bounds: Rect) -> Vec<Polygon> {
+    let (unique, faces) = delaunay_faces(points);
+    let mut neighbours = vec![vec![]; unique.len()];
+    for (a, b) in faces.iter().flat_map(|face| face.edges()) {
+        if !neighbours[a].contains(&b) {
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+        }
+    }
+    let corners: Vec<Point> = bounds
+        .as_polygon()
+        .points()
+        .iter()
+        .map(|point| (point.x as f64, point.y as f64))
+        .collect();
+    points
+        .iter()
+        .map(|point| {
+            let index =
+                unique.binary_search_by_key(&(point.x, point.y), |point| (point.x, point.y));
+            let site = (point.x as f64, point.y as f64);
+            let mut cell = corners.clone();
+            let others: Vec<Coord> = match index {
+                Ok(index) if faces.is_empty() => unique
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != index)
+                    .map(|(_, point)| *point)
+                    .collect(),
+                Ok(index) => neighbours[index].iter().map(|i| unique[*i]).collect(),
+                Err(_) => vec![],
+            };
+            for other in others {
+                cell = clip_closer(&cell, site, (other.x as f64, other.y as f64));
+            }
+            let mut points: Vec<Coord> = cell
+                .iter()
+                .map(|point| coord!(point.0.round(), point.1.round()))
+                .collect();
+            points.dedup();
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+            Polygon::new(&points)
+        })
+        .collect()
+}
+
+/// Keep only the part of the convex polygon `points` that is closer to `site` than `other`
+fn clip_closer(points: &[Point], site: Point, other: Point) -> Vec<Point> {
+    let mid = ((site.0 + other.0) / 2.0, (site.1 + other.1) / 2.0);
+    let dir = (other.0 - site.0, other.1 - site.1);
+    let side = |point: Point| (point.0 - mid.0) * dir.0 + (point.1 - mid.1) * dir.1;
+    let mut output = vec![];
+    for i in 0..points.len() {
+        let current = points[i];
+        let next = points[(i + 1) % points.len()];
+        let (current_side, next_side) = (side(current), side(next));
+        if current_side <= 0.0 {
+            output.push(current);
+        }
+        if (current_side < 0.0 && next_side > 0.0) || (current_side > 0.0 && next_side < 0.0) {
+            let t = current_side / (current_side - next_side);
+            output.push((
+                current.0 + (next.0 - current.0) * t,
+                current.1 + (next.1 - current.1) * t,
+            ));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn circumcircle_is_empty(triangle: &Triangle, points: &[Coord]) -> bool {
+        let corners = triangle.points();
+        let fpoints: Vec<Point> = corners
+            .iter()
+            .map(|point| (point.x as f64, point.y as f64))
+            .collect();
+        let face = Face::new([0, 1, 2], &fpoints).unwrap();
+        points
+            .iter()
+            .filter(|point| !corners.contains(point))
+            .all(|point| !face.in_circumcircle((point.x as f64, point.y as f64)))
+    }
+
+    fn grid_points() -> Vec<Coord> {
+        let mut points = vec![];
+        for i in 0..30 {
+            let x = (i * 37 % 101) as isize;
+            let y = (i * 61 % 89) as isize;
+            points.push(coord!(x, y));
+        }
+        points
+    }
+
+    #[test]
+    fn square() {
+        let triangles = delaunay(&[coord!(0, 0), coord!(10, 0), coord!(10, 10), coord!(0, 10)]);
+        assert_eq!(triangles.len(), 2);
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(delaunay(&[]).is_empty());
+        assert!(delaunay(&[coord!(0, 0), coord!(10, 0)]).is_empty());
+        assert!(delaunay(&[coord!(0, 0), coord!(10, 0), coord!(20, 0)]).is_empty());
+        assert_eq!(
+            delaunay(&[coord!(0, 0), coord!(10, 0), coord!(0, 10), coord!(0, 10)]).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn delaunay_property() {
+        let points = grid_points();
+        let triangles = delaunay(&points);
+        assert!(triangles.len() > points.len());
+        for triangle in &triangles {
+            assert!(circumcircle_is_empty(triangle, &points));
+        }
+        let area: f32 = triangles
+            .iter()
+            .map(|triangle| Polygon::new(&triangle.points()).area())
+            .sum();
+        assert!((area - Polygon::convex_hull(&points).area()).abs() < 1.0);
+    }
+
+    #[test]
+    fn voronoi_cells() {
+        let bounds = Rect::new((0, 0), (100, 100));
+        let points = grid_points();
+        let cells = voronoi(&points, bounds.clone());
+        assert_eq!(cells.len(), points.len());
+        let total: f32 = cells.iter().map(|cell| cell.area()).sum();
+        assert!((total - 10000.0).abs() < 100.0, "{total}");
+        for (cell, point) in cells.iter().zip(&points) {
+            assert!(cell.is_convex());
+            let center = cell.center();
+            let closest = points
+                .iter()
+                .min_by_key(|other| {
+                    let diff = **other - center;
+                    diff.x * diff.x + diff.y * diff.y
+                })
+                .unwrap();
+            let diff_own = *point - center;
+            let diff_closest = *closest - center;
+            // allow for rounding of the cell corners
+            let own = ((diff_own.x * diff_own.x + diff_own.y * diff_own.y) as f32).sqrt();
+            let best =
+                ((diff_closest.x * diff_closest.x + diff_closest.y * diff_closest.y) as f32).sqrt();
+            assert!(own - best < 2.0);
+        }
+    }
+
+    #[test]
+    fn voronoi_collinear() {
+        let cells = voronoi(
+            &[coord!(10, 50), coord!(50, 50), coord!(90, 50)],
+            Rect::new((0, 0), (100, 100)),
+        );
+        assert_eq!(cells[0].right(), 30);
+        assert_eq!(cells[1].left(), 30);
+        assert_eq!(cells[1].right(), 70);
+        assert_eq!(cells[2].left(), 70);
+        let single = voronoi(&[coord!(10, 50)], Rect::new((0, 0), (100, 100)));
+        assert_eq!(single[0].area(), 10000.0);
+    }
+}
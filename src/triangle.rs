@@ -1,3 +1,4 @@
+use crate::general_math::polygon_signed_distance;
 use crate::new_hash_set;
 use crate::prelude::*;
 use crate::shape_box::ShapeBox;
@@ -178,6 +179,10 @@ impl Shape for Triangle {
         output.into_iter().collect()
     }
 
+    fn signed_distance(&self, point: Coord) -> f32 {
+        polygon_signed_distance(&self.points(), point)
+    }
+
     fn to_shape_box(&self) -> ShapeBox {
         ShapeBox::Triangle(self.clone())
     }
@@ -263,6 +268,14 @@ impl Triangle {
         }
     }
 
+    /// Create an equilateral triangle with each corner `radius` away from `center`
+    ///
+    /// With a `rotation` of 0 the first corner is directly above `center`, see [Polygon::regular]
+    #[must_use]
+    pub fn regular<P: Into<Coord>>(center: P, radius: usize, rotation: isize) -> Triangle {
+        Triangle::from_points(&Polygon::regular(center, radius, 3, rotation).points())
+    }
+
     /// Create an equilateral triangle with width and height of [size] around [center]
     /// The top left would be (center.x - size / 2, center.y + size / 2) and bottom right (center.x + size / 2, center.y + size / 2)
     #[must_use]
@@ -318,7 +331,7 @@ fn draw_flat_top(output: &mut FnvHashSet<Coord>, points: [(f32, f32); 3]) {
 
 #[cfg(test)]
 mod test {
-    use crate::triangle::{AnglePosition, FlatSide, Triangle};
+    use crate::triangle::{AnglePosition, FlatSide, Triangle, TriangleSideType};
     use crate::Shape;
 
     #[test]
@@ -360,6 +373,15 @@ mod test {
         assert_eq!(triangle.points[2], (100, 0).into(), "bottomleft - same x");
     }
 
+    #[test]
+    fn regular() {
+        let triangle = Triangle::regular((100, 100), 50, 0);
+        assert_eq!(triangle.points[0], coord!(100, 50));
+        assert_eq!(triangle.side_type(), &TriangleSideType::Equilateral);
+        let rotated = Triangle::regular((100, 100), 50, 180);
+        assert_eq!(rotated.points[0], coord!(100, 150));
+    }
+
     #[test]
     fn check_moving() {
         let triangle = Triangle::equilateral((50, 50), 10, FlatSide::Left);
@@ -0,0 +1,145 @@
+use crate::distance::{add, fcoord, round, scale, Point};
+use crate::prelude::*;
+use crate::ray::cast_segment;
+use crate::shape_box::ShapeBox;
+
+/// Small angle (in radians) either side of each corner, so rays see past the corner as well as hitting it
+const CORNER_OFFSET: f32 = 0.0001;
+
+/// Region that can be seen from `origin`, with `obstacles` blocking the view and `bounds` limiting it
+///
+/// Used for lighting, fog of war, line of sight areas, etc
+///
+/// Calculated by an angular sweep, rays are cast at (and just either side of) every corner of every
+/// obstacle and the closest hits are joined. Curved obstacles are approximated with polygons.
+/// `origin` should be inside `bounds` and not inside an obstacle
+///
+/// # Usage
+/// ```rust
+///# use graphics_shapes::prelude::*;
+///# use graphics_shapes::shape_box::ShapeBox;
+///# use graphics_shapes::visibility::visibility_polygon;
+/// let bounds = Rect::new((0, 0), (100, 100));
+/// let pillar = ShapeBox::from(Rect::new((60, 40), (70, 60)));
+/// let visible = visibility_polygon(coord!(20, 50), &[pillar], bounds);
+/// assert!(visible.contains(coord!(50, 50)));
+/// assert!(!visible.contains(coord!(90, 50)));
+/// assert!(visible.contains(coord!(90, 10)));
+/// ```
+#[must_use]
+pub fn visibility_polygon(origin: Coord, obstacles: &[ShapeBox], bounds: Rect) -> Polygon {
+    let mut segments = lines_to_segments(&bounds.as_lines());
+    for obstacle in obstacles {
+        add_segments(&mut segments, obstacle);
+    }
+
+    let center = fcoord(origin);
+    let mut angles: Vec<f32> = segments
+        .iter()
+        .flat_map(|(start, end)| [*start, *end])
+        .flat_map(|(x, y)| {
+            let angle = (y - center.1).atan2(x - center.0);
+            [angle - CORNER_OFFSET, angle, angle + CORNER_OFFSET]
+        })
+        .collect();
+    angles.sort_by(f32::total_cmp);
+    angles.dedup();
+
+    let mut points: Vec<Coord> = vec![];
+    for angle in angles {
+        let ray = Ray::new(origin, (angle.cos(), angle.sin()));
+        let closest = segments
+            .iter()
+            .filter_map(|(start, end)| cast_segment(&ray, *start, *end))
+            .map(|(distance, _)| distance)
+            .min_by(f32::total_cmp);
+        if let Some(distance) = closest {
+            let point = round(add(center, scale(ray.direction(), distance)));
+            if points.last() != Some(&point) {
+                points.push(point);
+            }
+        }
+    }
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    Polygon::new(&points)
+}
+
+fn lines_to_segments(lines: &[Line]) -> Vec<(Point, Point)> {
+    lines
+        .iter()
+        .map(|line| (fcoord(line.start()), fcoord(line.end())))
+        .collect()
+}
+
+fn add_segments(segments: &mut Vec<(Point, Point)>, obstacle: &ShapeBox) {
+    let lines = match obstacle {
+        ShapeBox::Line(line) => vec![line.clone()],
+        ShapeBox::Rect(rect) => rect.as_lines().to_vec(),
+        ShapeBox::Triangle(triangle) => triangle.as_lines().to_vec(),
+        ShapeBox::Circle(circle) => circle.as_polygon().as_lines(),
+        ShapeBox::Ellipse(ellipse) => ellipse.as_polygon().as_lines(),
+        ShapeBox::Polygon(polygon) => polygon.as_lines(),
+        ShapeBox::Annulus(annulus) => {
+            let mut lines = annulus.as_outer_circle().as_polygon().as_lines();
+            lines.extend(annulus.as_inner_circle().as_polygon().as_lines());
+            lines
+        }
+        ShapeBox::Capsule(capsule) => capsule.as_polygon().as_lines(),
+        ShapeBox::PolygonWithHoles(polygon) => polygon.as_lines(),
+        ShapeBox::Group(group) => {
+            for shape in group.shapes() {
+                add_segments(segments, shape);
+            }
+            return;
+        }
+    };
+    segments.extend(lines_to_segments(&lines));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn empty_room() {
+        let bounds = Rect::new((0, 0), (100, 50));
+        let visible = visibility_polygon(coord!(30, 20), &[], bounds.clone());
+        assert_eq!(visible.left(), 0);
+        assert_eq!(visible.right(), 100);
+        assert_eq!(visible.top(), 0);
+        assert_eq!(visible.bottom(), 50);
+        assert!((visible.area() - 5000.0).abs() < 50.0);
+        assert_eq!(visible.winding(), Winding::Clockwise);
+    }
+
+    #[test]
+    fn shadow() {
+        let bounds = Rect::new((0, 0), (100, 100));
+        let wall = ShapeBox::from(Line::new((50, 30), (50, 70)));
+        let visible = visibility_polygon(coord!(20, 50), &[wall], bounds);
+        assert!(visible.contains(coord!(40, 50)));
+        assert!(!visible.contains(coord!(60, 50)));
+        assert!(!visible.contains(coord!(95, 50)));
+        // at the edges of the shadow
+        assert!(visible.contains(coord!(60, 20)));
+        assert!(visible.contains(coord!(60, 80)));
+        assert!(!visible.contains(coord!(80, 50)));
+    }
+
+    #[test]
+    fn curved_and_grouped() {
+        let bounds = Rect::new((0, 0), (200, 200));
+        let group = ShapeBox::from(crate::shape_group::ShapeGroup::new(&[
+            ShapeBox::from(Circle::new((100, 100), 20)),
+            ShapeBox::from(Ellipse::new((100, 30), 40, 20)),
+        ]));
+        let visible = visibility_polygon(coord!(100, 180), &[group], bounds);
+        assert!(visible.contains(coord!(100, 150)));
+        assert!(!visible.contains(coord!(100, 70)));
+        assert!(!visible.contains(coord!(100, 10)));
+        assert!(visible.contains(coord!(20, 20)));
+        assert!(visible.contains(coord!(180, 20)));
+    }
+}